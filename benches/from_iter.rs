@@ -0,0 +1,33 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use const_array::ConstArray;
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    println!("{name}: {:?}/iter", start.elapsed() / ITERATIONS);
+}
+
+fn main() {
+    bench("from_exact_iter", || {
+        let arr = ConstArray::<u32, 256>::from_exact_iter(black_box(0..256u32));
+        black_box(arr.unwrap());
+    });
+
+    bench("push_back loop", || {
+        let mut arr = ConstArray::<u32, 256>::uninit();
+
+        for i in black_box(0..256u32) {
+            let _ = arr.push_back(i);
+        }
+
+        black_box(arr);
+    });
+}
@@ -0,0 +1,65 @@
+use crate::ConstArray;
+
+impl<const N: usize> ConstArray<bool, N> {
+    /// Packs the initialized flags into a `u64`, where bit `i` is set if item `i` is `true`.
+    ///
+    /// `N` must not exceed 64, which is checked at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let flags = ConstArray::<bool, 8>::new([true, false, true, true]);
+    ///
+    /// assert_eq!(flags.to_bitmask_u64(), 0b1101);
+    /// ```
+    #[must_use]
+    pub const fn to_bitmask_u64(&self) -> u64 {
+        const { assert!(N <= 64, "bitmask capacity exceeds 64 bits") };
+
+        let flags = self.as_slice();
+        let mut bits = 0;
+        let mut i = 0;
+
+        while i < flags.len() {
+            if flags[i] {
+                bits |= 1 << i;
+            }
+
+            i += 1;
+        }
+
+        bits
+    }
+
+    /// Unpacks the lowest `len` bits of `bits` into flags, where item `i` is `true` if bit `i`
+    /// is set.
+    ///
+    /// Returns `None` if `len` exceeds the capacity. `N` must not exceed 64, which is checked at
+    /// compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let flags = ConstArray::<bool, 8>::from_bitmask_u64(0b1101, 5).unwrap();
+    ///
+    /// assert_eq!(flags.as_slice(), &[true, false, true, true, false]);
+    /// assert_eq!(flags.to_bitmask_u64(), 0b1101);
+    /// assert!(ConstArray::<bool, 8>::from_bitmask_u64(0, 9).is_none());
+    /// ```
+    #[must_use]
+    pub const fn from_bitmask_u64(bits: u64, len: usize) -> Option<Self> {
+        const { assert!(N <= 64, "bitmask capacity exceeds 64 bits") };
+
+        if len > N {
+            return None;
+        }
+
+        let mut flags = Self::uninit();
+
+        while flags.len() < len {
+            let _ = flags.push_back(bits & (1 << flags.len()) != 0);
+        }
+
+        Some(flags)
+    }
+}
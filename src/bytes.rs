@@ -0,0 +1,352 @@
+use core::fmt;
+
+use crate::{CapacityError, ConstArray};
+
+/// Generates a const method reading a fixed-width integer from the initialized bytes
+macro_rules! read_int {
+    ($name:ident, $int:ty, $from:ident, $endian:literal, $bytes:expr, $value:expr) => {
+        #[doc = concat!("Reads a ", $endian, "-endian [`", stringify!($int), "`] at `offset`.")]
+        ///
+        /// Returns `None` if the read extends past the initialized bytes.
+        ///
+        /// # Example
+        /// ```rust
+        /// # use const_array::ConstArray;
+        #[doc = concat!("let arr = ConstArray::<u8, 16>::new(", stringify!($bytes), ");")]
+        ///
+        #[doc = concat!("assert_eq!(arr.", stringify!($name), "(1), Some(", stringify!($value), "));")]
+        #[doc = concat!("assert_eq!(arr.", stringify!($name), "(2), None);")]
+        /// ```
+        #[must_use]
+        pub const fn $name(&self, offset: usize) -> Option<$int> {
+            match self.read_bytes(offset) {
+                Some(bytes) => Some(<$int>::$from(bytes)),
+                None => None,
+            }
+        }
+    };
+}
+
+impl<const N: usize> ConstArray<u8, N> {
+    read_int!(
+        read_u16_le,
+        u16,
+        from_le_bytes,
+        "little",
+        [0, 0x34, 0x12],
+        0x1234
+    );
+    read_int!(
+        read_u16_be,
+        u16,
+        from_be_bytes,
+        "big",
+        [0, 0x12, 0x34],
+        0x1234
+    );
+    read_int!(
+        read_u32_le,
+        u32,
+        from_le_bytes,
+        "little",
+        [0, 0x78, 0x56, 0x34, 0x12],
+        0x1234_5678
+    );
+    read_int!(
+        read_u32_be,
+        u32,
+        from_be_bytes,
+        "big",
+        [0, 0x12, 0x34, 0x56, 0x78],
+        0x1234_5678
+    );
+    read_int!(
+        read_u64_le,
+        u64,
+        from_le_bytes,
+        "little",
+        [0, 0xef, 0xcd, 0xab, 0x90, 0x78, 0x56, 0x34, 0x12],
+        0x1234_5678_90ab_cdef
+    );
+    read_int!(
+        read_u64_be,
+        u64,
+        from_be_bytes,
+        "big",
+        [0, 0x12, 0x34, 0x56, 0x78, 0x90, 0xab, 0xcd, 0xef],
+        0x1234_5678_90ab_cdef
+    );
+
+    /// Returns the number of initialized bytes.
+    ///
+    /// This is the same as [`ConstArray::len()`], but makes it explicit that a byte count is
+    /// meant, for example when filling in length fields of a protocol header.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static PAYLOAD: ConstArray<u8, 16> = ConstArray::new(*b"hello");
+    ///
+    /// const LEN: usize = PAYLOAD.byte_len();
+    /// const _: () = assert!(LEN == 5);
+    /// ```
+    #[must_use]
+    pub const fn byte_len(&self) -> usize {
+        self.len()
+    }
+
+    /// Compares the initialized bytes against `other` in a const context.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static MAGIC: ConstArray<u8, 8> = ConstArray::new(*b"\x7fELF");
+    ///
+    /// const _: () = assert!(MAGIC.const_eq_bytes(b"\x7fELF"));
+    /// const _: () = assert!(!MAGIC.const_eq_bytes(b"\x7fEL"));
+    /// const _: () = assert!(!MAGIC.const_eq_bytes(b"\x7fELG"));
+    /// ```
+    #[must_use]
+    pub const fn const_eq_bytes(&self, other: &[u8]) -> bool {
+        bytes_eq(self.as_slice(), other)
+    }
+
+    /// Returns `true` if the initialized bytes start with `prefix`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static PACKET: ConstArray<u8, 8> = ConstArray::new(*b"\x89PNG");
+    ///
+    /// const _: () = assert!(PACKET.starts_with_bytes(b"\x89P"));
+    /// const _: () = assert!(PACKET.starts_with_bytes(b""));
+    /// const _: () = assert!(!PACKET.starts_with_bytes(b"PNG"));
+    /// const _: () = assert!(!PACKET.starts_with_bytes(b"\x89PNG\r\n"));
+    /// ```
+    #[must_use]
+    pub const fn starts_with_bytes(&self, prefix: &[u8]) -> bool {
+        match self.as_slice().split_at_checked(prefix.len()) {
+            Some((head, _)) => bytes_eq(head, prefix),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the initialized bytes end with `suffix`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static PACKET: ConstArray<u8, 8> = ConstArray::new(*b"\x89PNG");
+    ///
+    /// const _: () = assert!(PACKET.ends_with_bytes(b"NG"));
+    /// const _: () = assert!(PACKET.ends_with_bytes(b""));
+    /// const _: () = assert!(!PACKET.ends_with_bytes(b"\x89P"));
+    /// const _: () = assert!(!PACKET.ends_with_bytes(b"\0\x89PNG"));
+    /// ```
+    #[must_use]
+    pub const fn ends_with_bytes(&self, suffix: &[u8]) -> bool {
+        let bytes = self.as_slice();
+
+        match bytes.len().checked_sub(suffix.len()) {
+            Some(start) => bytes_eq(bytes.split_at(start).1, suffix),
+            None => false,
+        }
+    }
+
+    /// Interprets the initialized bytes as UTF-8, returning an error if they aren't valid.
+    ///
+    /// # Errors
+    /// Returns a [`Utf8Error`](core::str::Utf8Error) describing the first invalid sequence.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let text = ConstArray::<u8, 16>::new(*b"caf\xc3\xa9");
+    /// let truncated = ConstArray::<u8, 16>::new(*b"caf\xc3");
+    ///
+    /// assert_eq!(text.as_str(), Ok("café"));
+    /// assert_eq!(truncated.as_str().unwrap_err().valid_up_to(), 3);
+    /// ```
+    pub const fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_slice())
+    }
+
+    /// Appends the UTF-8 bytes of `s`, or appends nothing if they don't all fit.
+    ///
+    /// # Errors
+    /// Returns a [`CapacityError`] if fewer than `s.len()` bytes of capacity are left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut text = ConstArray::<u8, 8>::uninit();
+    ///
+    /// assert!(text.push_str("caf").is_ok());
+    /// assert!(text.push_str("é!").is_ok());
+    /// assert!(text.push_str("???").is_err());
+    ///
+    /// assert_eq!(text.as_str(), Ok("café!"));
+    /// ```
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError<()>> {
+        self.ensure_capacity(s.len())?;
+
+        for &byte in s.as_bytes() {
+            // The capacity was checked above, so every push succeeds
+            let _ = self.push_back(byte);
+        }
+
+        Ok(())
+    }
+
+    /// Counts the differing bits between two byte arrays of equal length.
+    ///
+    /// Returns `None` if the lengths differ.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static A: ConstArray<u8, 4> = ConstArray::new([0b1010, 0xFF]);
+    /// static B: ConstArray<u8, 4> = ConstArray::new([0b0110, 0x0F]);
+    /// static SHORT: ConstArray<u8, 4> = ConstArray::new([0b1010]);
+    ///
+    /// const _: () = assert!(matches!(A.hamming_distance(&B), Some(6)));
+    /// const _: () = assert!(matches!(A.hamming_distance(&A), Some(0)));
+    /// const _: () = assert!(A.hamming_distance(&SHORT).is_none());
+    /// ```
+    #[must_use]
+    pub const fn hamming_distance(&self, other: &Self) -> Option<u32> {
+        let (a, b) = (self.as_slice(), other.as_slice());
+
+        if a.len() != b.len() {
+            return None;
+        }
+
+        let mut distance = 0;
+        let mut i = 0;
+
+        while i < a.len() {
+            distance += (a[i] ^ b[i]).count_ones();
+            i += 1;
+        }
+
+        Some(distance)
+    }
+
+    /// XORs all initialized bytes together, returning `0` if the array is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static FRAME: ConstArray<u8, 8> = ConstArray::new([0x01, 0x02, 0x04, 0x0F]);
+    /// static EMPTY: ConstArray<u8, 8> = ConstArray::uninit();
+    ///
+    /// const _: () = assert!(FRAME.xor_fold() == 0x08);
+    /// const _: () = assert!(EMPTY.xor_fold() == 0);
+    /// ```
+    #[must_use]
+    pub const fn xor_fold(&self) -> u8 {
+        let bytes = self.as_slice();
+        let mut checksum = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            checksum ^= bytes[i];
+            i += 1;
+        }
+
+        checksum
+    }
+
+    /// Copies `W` initialized bytes starting at `offset`, or returns `None` if out of range.
+    const fn read_bytes<const W: usize>(&self, offset: usize) -> Option<[u8; W]> {
+        let Some(end) = offset.checked_add(W) else {
+            return None;
+        };
+
+        if end > self.len() {
+            return None;
+        }
+
+        let mut bytes = [0; W];
+        let mut i = 0;
+
+        while i < W {
+            bytes[i] = self.as_slice()[offset + i];
+            i += 1;
+        }
+
+        Some(bytes)
+    }
+
+    /// Returns a value that formats the initialized bytes as lowercase hex.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([0x00, 0xde, 0xad, 0xbe, 0xef]);
+    ///
+    /// assert_eq!(arr.display_hex().to_string(), "00deadbeef");
+    /// ```
+    #[must_use]
+    pub fn display_hex(&self) -> impl fmt::Display + '_ {
+        Hex {
+            bytes: self.as_slice(),
+            upper: false,
+        }
+    }
+
+    /// Returns a value that formats the initialized bytes as uppercase hex.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([0x00, 0xde, 0xad, 0xbe, 0xef]);
+    ///
+    /// assert_eq!(arr.display_hex_upper().to_string(), "00DEADBEEF");
+    /// ```
+    #[must_use]
+    pub fn display_hex_upper(&self) -> impl fmt::Display + '_ {
+        Hex {
+            bytes: self.as_slice(),
+            upper: true,
+        }
+    }
+}
+
+/// Compares two byte slices in a const context
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+struct Hex<'a> {
+    bytes: &'a [u8],
+    upper: bool,
+}
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.bytes {
+            if self.upper {
+                write!(f, "{byte:02X}")?;
+            } else {
+                write!(f, "{byte:02x}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::ConstArray;
+
+impl<T: ConstantTimeEq, const N: usize> ConstantTimeEq for ConstArray<T, N> {
+    /// Compares the initialized items in constant time.
+    ///
+    /// The time taken only depends on the lengths of the arrays, which are not secret. Arrays
+    /// of differing lengths are never equal.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// use subtle::ConstantTimeEq;
+    ///
+    /// let key = ConstArray::<u8, 32>::new([1, 2, 3, 4]);
+    ///
+    /// assert!(bool::from(key.ct_eq(&ConstArray::new([1, 2, 3, 4]))));
+    /// assert!(!bool::from(key.ct_eq(&ConstArray::new([1, 2, 3, 5]))));
+    /// assert!(!bool::from(key.ct_eq(&ConstArray::new([1, 2, 3]))));
+    /// ```
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
@@ -0,0 +1,38 @@
+use core::fmt;
+
+/// Error returned when an operation would exceed the capacity of a [`ConstArray`].
+///
+/// The element that did not fit is kept inside the error, and can be recovered
+/// with [`CapacityError::element()`].
+///
+/// [`ConstArray`]: crate::ConstArray
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates a new error holding the passed element.
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    /// Consumes the error, returning the element that did not fit.
+    pub fn element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "insufficient capacity")
+    }
+}
+
+impl<T> core::error::Error for CapacityError<T> {}
@@ -0,0 +1,91 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::ConstArray;
+
+/// A fixed-size array that is proven to have all `N` slots initialized.
+///
+/// Created from a full [`ConstArray`] through [`TryFrom`], which lets an API require a
+/// completely filled buffer in its signature.
+///
+/// # Example
+/// ```rust
+/// # use const_array::{ConstArray, FullArray};
+/// let full = FullArray::try_from(ConstArray::from_array([1u32, 2, 3])).unwrap();
+/// assert_eq!(*full, [1, 2, 3]);
+///
+/// let partial = ConstArray::<u32, 3>::new([1, 2]);
+/// let arr = FullArray::try_from(partial).unwrap_err();
+/// assert_eq!(arr.as_slice(), &[1, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FullArray<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> FullArray<T, N> {
+    /// Wraps a plain array, which is always full.
+    pub const fn new(array: [T; N]) -> Self {
+        Self(array)
+    }
+
+    /// Consumes the wrapper, returning the plain array.
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> TryFrom<ConstArray<T, N>> for FullArray<T, N> {
+    type Error = ConstArray<T, N>;
+
+    /// Converts a full array, or returns it back in the `Err` if it is not full.
+    fn try_from(arr: ConstArray<T, N>) -> Result<Self, ConstArray<T, N>> {
+        if !arr.is_full() {
+            return Err(arr);
+        }
+
+        let buf = arr.to_array();
+
+        // SAFETY: All `N` items are initialized, and `MaybeUninit<T>` has the same layout as `T`
+        Ok(Self(unsafe {
+            core::ptr::read((&raw const buf).cast::<[T; N]>())
+        }))
+    }
+}
+
+impl<T, const N: usize> TryFrom<ConstArray<T, N>> for [T; N] {
+    type Error = ConstArray<T, N>;
+
+    /// Moves the items of a full array into a plain array, or returns it back in the `Err`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let array: [u32; 3] = ConstArray::from_array([1, 2, 3]).try_into().unwrap();
+    /// assert_eq!(array, [1, 2, 3]);
+    ///
+    /// let arr = ConstArray::<u32, 3>::new([1, 2]);
+    /// let arr = <[u32; 3]>::try_from(arr).unwrap_err();
+    /// assert_eq!(arr.as_slice(), &[1, 2]);
+    /// ```
+    fn try_from(arr: ConstArray<T, N>) -> Result<Self, ConstArray<T, N>> {
+        FullArray::try_from(arr).map(FullArray::into_inner)
+    }
+}
+
+impl<T, const N: usize> From<FullArray<T, N>> for ConstArray<T, N> {
+    fn from(full: FullArray<T, N>) -> Self {
+        Self::from_array(full.0)
+    }
+}
+
+impl<T, const N: usize> Deref for FullArray<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for FullArray<T, N> {
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
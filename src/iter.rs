@@ -0,0 +1,281 @@
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+
+use crate::ConstArray;
+
+/// An iterator that moves the items out of a [`ConstArray`].
+///
+/// Created by the [`IntoIterator`] implementation for [`ConstArray`]. Any items that are not
+/// yielded are dropped along with the iterator.
+pub struct IntoIter<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    /// Index of the next item yielded by [`Iterator::next()`]
+    front: usize,
+    /// One past the index of the next item yielded by [`DoubleEndedIterator::next_back()`]
+    back: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Returns the items that have not been yielded yet as a slice
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut iter = ConstArray::from_array([1u32, 2, 3]).into_iter();
+    ///
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.as_slice(), &[2, 3]);
+    /// ```
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: Items in `front..back` are initialized and have not been moved out
+        unsafe {
+            core::slice::from_raw_parts(
+                self.buf.as_ptr().add(self.front).cast::<T>(),
+                self.back - self.front,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY: `front < back`, so the item is initialized, and it is never read again
+        let item = unsafe { self.buf[self.front].assume_init_read() };
+        self.front += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        // SAFETY: `front < back` held before the decrement, so the item is initialized and unread
+        Some(unsafe { self.buf[self.back].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        for i in self.front..self.back {
+            // SAFETY: Only the items that were not yielded are dropped
+            unsafe { self.buf[i].assume_init_drop() }
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ConstArray<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Creates an iterator that moves the items out of the array, from front to back.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4]);
+    /// let mut iter = arr.into_iter();
+    ///
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(4));
+    /// assert_eq!(iter.rev().collect::<Vec<_>>(), vec![3, 2]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T, N> {
+        let (buf, len) = self.into_raw_parts();
+
+        IntoIter {
+            buf,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+/// Removes items from the front of a [`ConstArray`] while a predicate holds.
+///
+/// Created by [`ConstArray::drain_while()`]. The remaining items are shifted to the front
+/// when this is dropped.
+pub(crate) struct DrainWhile<'a, T, const N: usize, F> {
+    arr: &'a mut ConstArray<T, N>,
+    /// Number of items moved out of the front
+    taken: usize,
+    /// Length of the array before draining
+    len: usize,
+    pred: F,
+    done: bool,
+}
+
+impl<'a, T, const N: usize, F: FnMut(&T) -> bool> DrainWhile<'a, T, N, F> {
+    pub(crate) fn new(arr: &'a mut ConstArray<T, N>, pred: F) -> Self {
+        let len = arr.len;
+
+        // The items are owned by the drain until it is dropped, so a leaked drain can't cause a double drop
+        arr.len = 0;
+
+        Self {
+            arr,
+            taken: 0,
+            len,
+            pred,
+            done: false,
+        }
+    }
+}
+
+impl<T, const N: usize, F: FnMut(&T) -> bool> Iterator for DrainWhile<'_, T, N, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done || self.taken == self.len {
+            return None;
+        }
+
+        // SAFETY: Items in `taken..len` are initialized and have not been moved out
+        let front = unsafe { self.arr.buf[self.taken].assume_init_ref() };
+
+        if !(self.pred)(front) {
+            self.done = true;
+            return None;
+        }
+
+        // SAFETY: As above, and `taken` is incremented so the item is never read again
+        let item = unsafe { self.arr.buf[self.taken].assume_init_read() };
+        self.taken += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, Some(self.len - self.taken))
+        }
+    }
+}
+
+impl<T, const N: usize, F> Drop for DrainWhile<'_, T, N, F> {
+    fn drop(&mut self) {
+        let remaining = self.len - self.taken;
+
+        // SAFETY: The remaining items are moved to the front, over the slots that were moved out of
+        unsafe {
+            let ptr = self.arr.buf.as_mut_ptr();
+            core::ptr::copy(ptr.add(self.taken), ptr, remaining);
+        }
+
+        self.arr.len = remaining;
+    }
+}
+
+/// Removes the items of a [`ConstArray`] that match a predicate, yielding them lazily.
+///
+/// Created by [`ConstArray::extract_if()`]. When dropped, the items that were not examined
+/// yet are kept and shifted down behind the kept items.
+pub(crate) struct ExtractIf<'a, T, const N: usize, F> {
+    arr: &'a mut ConstArray<T, N>,
+    /// Index of the next item to examine
+    read: usize,
+    /// Number of examined items that were kept, which are compacted to the front
+    write: usize,
+    /// Length of the array before extracting
+    len: usize,
+    pred: F,
+}
+
+impl<'a, T, const N: usize, F: FnMut(&mut T) -> bool> ExtractIf<'a, T, N, F> {
+    pub(crate) fn new(arr: &'a mut ConstArray<T, N>, pred: F) -> Self {
+        let len = arr.len;
+
+        // The items are owned by the iterator until it is dropped, so a leaked iterator can't cause a double drop
+        arr.len = 0;
+
+        Self {
+            arr,
+            read: 0,
+            write: 0,
+            len,
+            pred,
+        }
+    }
+}
+
+impl<T, const N: usize, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, N, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.read < self.len {
+            // SAFETY: Items in `read..len` are initialized and have not been moved out
+            let item = unsafe { self.arr.buf[self.read].assume_init_mut() };
+
+            if (self.pred)(item) {
+                // SAFETY: As above, and `read` is incremented so the item is never read again
+                let item = unsafe { self.arr.buf[self.read].assume_init_read() };
+                self.read += 1;
+
+                return Some(item);
+            }
+
+            if self.read != self.write {
+                // SAFETY: `write < read`, so the slot at `write` has already been moved out of
+                unsafe {
+                    let ptr = self.arr.buf.as_mut_ptr();
+                    core::ptr::copy_nonoverlapping(ptr.add(self.read), ptr.add(self.write), 1);
+                }
+            }
+
+            self.read += 1;
+            self.write += 1;
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.read))
+    }
+}
+
+impl<T, const N: usize, F> Drop for ExtractIf<'_, T, N, F> {
+    fn drop(&mut self) {
+        let remaining = self.len - self.read;
+
+        // SAFETY: The unexamined items are moved down behind the kept ones
+        unsafe {
+            let ptr = self.arr.buf.as_mut_ptr();
+            core::ptr::copy(ptr.add(self.read), ptr.add(self.write), remaining);
+        }
+
+        self.arr.len = self.write + remaining;
+    }
+}
@@ -1,24 +1,99 @@
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::cmp::Ordering;
 use core::hash::Hasher;
-use core::mem::MaybeUninit;
-use core::ops::{Deref, DerefMut};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{AddAssign, Bound, Deref, DerefMut, MulAssign, Range, RangeBounds};
+
+mod bits;
+mod bytes;
+#[cfg(feature = "subtle")]
+mod ct;
+mod error;
+mod full;
+mod iter;
+mod num;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use error::CapacityError;
+pub use full::FullArray;
+pub use iter::IntoIter;
+#[cfg(feature = "serde")]
+pub use serde_impl::WithLen;
+
+/// The byte written to the spare capacity of new arrays with the `debug-canary` feature.
+///
+/// This only applies to debug builds, and has no effect in release builds.
+pub const CANARY: u8 = 0xAA;
 
+/// A fixed-capacity array of up to `N` items, usable in const contexts.
+///
+/// # Layout
+/// The array consists of a `[MaybeUninit<T>; N]` buffer and a `usize` length. By default the
+/// compiler is free to reorder these fields. With the `repr-c` feature the struct is
+/// `#[repr(C)]`, so the buffer is guaranteed to come first, followed by the length, which makes
+/// the type suitable for embedding in FFI structs.
+#[cfg_attr(feature = "repr-c", repr(C))]
 pub struct ConstArray<T, const N: usize> {
     buf: [MaybeUninit<T>; N],
     len: usize,
 }
 
 impl<T, const N: usize> ConstArray<T, N> {
+    /// The size of this array type in bytes, for compile-time layout assertions.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// const _: () = assert!(ConstArray::<u64, 4>::SIZE >= 4 * 8 + size_of::<usize>());
+    /// ```
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// The total capacity of this array type, for use without an instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// type Packet = ConstArray<u8, 16>;
+    ///
+    /// const _: () = assert!(Packet::CAPACITY == 16);
+    /// let scratch = [0u8; Packet::CAPACITY];
+    ///
+    /// assert_eq!(scratch.len(), Packet::uninit().capacity());
+    /// ```
+    pub const CAPACITY: usize = N;
+
     #[must_use]
     pub const fn uninit() -> Self {
         Self {
-            buf: [const { MaybeUninit::uninit() }; N],
+            buf: Self::spare_buf(),
             len: 0,
         }
     }
 
+    /// Returns an uninitialized buffer for a new array.
+    ///
+    /// With the `debug-canary` feature in debug builds, every byte is set to [`CANARY`] so that
+    /// reads of uninitialized slots are easy to spot in a debugger.
+    const fn spare_buf() -> [MaybeUninit<T>; N] {
+        #[allow(unused_mut)]
+        let mut buf = [const { MaybeUninit::uninit() }; N];
+
+        #[cfg(all(debug_assertions, feature = "debug-canary"))]
+        // SAFETY: Any bit pattern is valid for `MaybeUninit<T>`
+        unsafe {
+            core::ptr::write_bytes(buf.as_mut_ptr(), CANARY, N);
+        }
+
+        buf
+    }
+
     /// Creates a new array from any size of passed array
     ///
     /// # Example
@@ -30,11 +105,19 @@ impl<T, const N: usize> ConstArray<T, N> {
     /// assert_eq!(arr.len(), 3);
     /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
     /// ```
+    ///
+    /// Passing an array larger than the capacity fails to compile:
+    /// ```rust,compile_fail
+    /// # use const_array::ConstArray;
+    /// const ARR: ConstArray<u32, 2> = ConstArray::new([1, 2, 3]);
+    /// ```
     pub const fn new<const W: usize>(array: [T; W]) -> Self {
-        let mut buf = [const { MaybeUninit::uninit() }; N];
+        const { assert!(W <= N, "array does not fit in the capacity") };
+
+        let mut buf = Self::spare_buf();
         let mut i = 0;
 
-        // We already checked that W < N
+        // We already checked that W <= N
         while i < W {
             // SAFETY: We are manually dropping the passed array, and ensuring that it is small enough to fit
             let value = unsafe { core::ptr::read(&raw const array[i]) };
@@ -64,6 +147,164 @@ impl<T, const N: usize> ConstArray<T, N> {
         Self::new(array)
     }
 
+    /// Creates a new array from an array of length `W`, with a length of `W`.
+    ///
+    /// This is identical to [`ConstArray::new()`], but names the resulting length explicitly.
+    /// Stable Rust can't express `W <= N` as a `where` bound, so it is instead checked by a
+    /// const assertion when the function is instantiated.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::from_array_with_len([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.len(), 3);
+    /// assert_eq!(arr.capacity(), 8);
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use const_array::ConstArray;
+    /// const ARR: ConstArray<u32, 2> = ConstArray::from_array_with_len([1, 2, 3]);
+    /// ```
+    pub const fn from_array_with_len<const W: usize>(array: [T; W]) -> Self {
+        Self::new(array)
+    }
+
+    /// Creates a full array holding `N` copies of `value`.
+    ///
+    /// `value` is cloned `N - 1` times and then moved into the last slot. If a clone panics,
+    /// the already created items are dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 4>::filled(0);
+    ///
+    /// assert_eq!(arr.as_slice(), &[0, 0, 0, 0]);
+    /// assert!(arr.is_full());
+    /// ```
+    pub fn filled(value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut arr = Self::uninit();
+
+        if N == 0 {
+            return arr;
+        }
+
+        while arr.len < N - 1 {
+            arr.buf[arr.len] = MaybeUninit::new(value.clone());
+            arr.len += 1;
+        }
+
+        arr.buf[arr.len] = MaybeUninit::new(value);
+        arr.len += 1;
+
+        arr
+    }
+
+    /// Creates a new array from an [`ExactSizeIterator`], checking the capacity once up front.
+    ///
+    /// # Errors
+    /// If the iterator reports more items than the array can hold, this function will Err
+    /// without consuming any items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 4>::from_exact_iter([1, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// assert!(ConstArray::<u32, 2>::from_exact_iter([1, 2, 3]).is_err());
+    /// # assert_eq!(arr.len(), 3);
+    /// ```
+    pub fn from_exact_iter<I>(iter: I) -> Result<Self, CapacityError<()>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+
+        if iter.len() > N {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut arr = Self::uninit();
+
+        // A faulty `ExactSizeIterator` may yield more than it reported, so the fill is still bounded
+        for (slot, item) in arr.buf.iter_mut().zip(iter) {
+            *slot = MaybeUninit::new(item);
+            arr.len += 1;
+        }
+
+        Ok(arr)
+    }
+
+    /// Creates a new array from an iterator, failing if it yields more items than fit.
+    ///
+    /// # Errors
+    /// If the iterator yields more than `N` items, this function will Err with the first item
+    /// that did not fit. Items that were already taken from the iterator are dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 4>::try_from_iter([1, 2, 3]).unwrap();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    ///
+    /// let err = ConstArray::<u32, 2>::try_from_iter([1, 2, 3, 4]).unwrap_err();
+    /// assert_eq!(err.element(), 3);
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T>> {
+        let mut arr = Self::uninit();
+
+        for item in iter {
+            arr.push_back(item).map_err(CapacityError::new)?;
+        }
+
+        Ok(arr)
+    }
+
+    /// Creates a new array by alternately taking items from `a` and `b`.
+    ///
+    /// Once one iterator is exhausted, the remaining items of the other are appended in order.
+    ///
+    /// # Errors
+    /// If the iterators yield more than `N` items in total, this function will Err, dropping the
+    /// items that were already taken.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::interleave([1, 3, 5, 7, 9], [2, 4]).unwrap();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5, 7, 9]);
+    ///
+    /// assert!(ConstArray::<u32, 3>::interleave([1, 3], [2, 4]).is_err());
+    /// ```
+    pub fn interleave<I, J>(a: I, b: J) -> Result<Self, CapacityError<()>>
+    where
+        I: IntoIterator<Item = T>,
+        J: IntoIterator<Item = T>,
+    {
+        let mut a = a.into_iter().fuse();
+        let mut b = b.into_iter().fuse();
+        let mut arr = Self::uninit();
+
+        loop {
+            let (first, second) = (a.next(), b.next());
+
+            if first.is_none() && second.is_none() {
+                return Ok(arr);
+            }
+
+            for item in first.into_iter().chain(second) {
+                arr.push_back(item).map_err(|_| CapacityError::new(()))?;
+            }
+        }
+    }
+
     /// Attemps to push an item to the front of this array.
     ///
     /// # Errors
@@ -91,6 +332,9 @@ impl<T, const N: usize> ConstArray<T, N> {
         }
 
         self.buf[0] = MaybeUninit::new(item);
+
+        // `len < N <= usize::MAX` since the array isn't full, so this can't overflow
+        debug_assert!(self.len < usize::MAX, "length overflowed");
         self.len += 1;
 
         Ok(())
@@ -117,11 +361,144 @@ impl<T, const N: usize> ConstArray<T, N> {
         }
 
         self.buf[self.len()] = MaybeUninit::new(item);
+
+        // `len < N <= usize::MAX` since the array isn't full, so this can't overflow
+        debug_assert!(self.len < usize::MAX, "length overflowed");
         self.len += 1;
 
         Ok(())
     }
 
+    /// Pushes an item to the back of this array, evicting the front item if the array is full.
+    ///
+    /// This turns the array into a bounded FIFO that never rejects an item. The evicted item
+    /// is returned, and for a zero-capacity array the passed item is returned immediately.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 3>::uninit();
+    ///
+    /// assert_eq!(arr.push_back_overwrite(1), None);
+    /// assert_eq!(arr.push_back_overwrite(2), None);
+    /// assert_eq!(arr.push_back_overwrite(3), None);
+    /// assert_eq!(arr.push_back_overwrite(4), Some(1));
+    /// assert_eq!(arr.push_back_overwrite(5), Some(2));
+    ///
+    /// assert_eq!(arr.as_slice(), &[3, 4, 5]);
+    /// ```
+    pub fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+
+        // Pushing can only fail when `N == 0`, in which case nothing was evicted
+        match self.push_back(item) {
+            Ok(()) => evicted,
+            Err(item) => Some(item),
+        }
+    }
+
+    /// Pushes an item to the back of this array, replacing the last item if the array is full.
+    ///
+    /// Unlike [`ConstArray::push_back_overwrite()`], nothing is shifted, and the newest item is
+    /// displaced rather than the oldest. The displaced item is returned, and for a
+    /// zero-capacity array the passed item is returned immediately.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut latest = ConstArray::<u32, 3>::new([1, 2, 3]);
+    /// let mut oldest = latest.clone();
+    ///
+    /// assert_eq!(latest.push_back_saturating(4), Some(3));
+    /// assert_eq!(oldest.push_back_overwrite(4), Some(1));
+    ///
+    /// assert_eq!(latest.as_slice(), &[1, 2, 4]);
+    /// assert_eq!(oldest.as_slice(), &[2, 3, 4]);
+    /// ```
+    pub fn push_back_saturating(&mut self, item: T) -> Option<T> {
+        match self.push_back(item) {
+            Ok(()) => None,
+            Err(item) => match self.as_mut_slice().last_mut() {
+                Some(last) => Some(core::mem::replace(last, item)),
+                None => Some(item),
+            },
+        }
+    }
+
+    /// Writes a sample into a tapped delay line, returning the oldest sample once full.
+    ///
+    /// This is the same operation as [`ConstArray::push_back_overwrite()`], named for DSP code.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut taps = ConstArray::<i16, 4>::uninit();
+    ///
+    /// for sample in [1, 2, 3, 4] {
+    ///     assert_eq!(taps.write_sample(sample), None);
+    /// }
+    ///
+    /// assert_eq!(taps.write_sample(5), Some(1));
+    /// assert_eq!(taps.as_slice(), &[2, 3, 4, 5]);
+    /// ```
+    pub fn write_sample(&mut self, sample: T) -> Option<T> {
+        self.push_back_overwrite(sample)
+    }
+
+    /// Attempts to push a `Copy` item to the back of this array, returning whether it fit.
+    ///
+    /// Since the caller still holds a copy of the item, there is no need to hand it back.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u8, 2>::uninit();
+    ///
+    /// assert!(arr.try_push_back_copy(1));
+    /// assert!(arr.try_push_back_copy(2));
+    /// assert!(!arr.try_push_back_copy(3));
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2]);
+    /// ```
+    pub const fn try_push_back_copy(&mut self, item: T) -> bool
+    where
+        T: Copy,
+    {
+        self.push_back(item).is_ok()
+    }
+
+    /// Pushes an item to the back of this array only if `cond` returns `true` for the array.
+    ///
+    /// `cond` is not called when the array is full.
+    ///
+    /// # Errors
+    /// If the array is full or `cond` returns `false`, this function will Err and return the
+    /// passed value
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 4>::uninit();
+    ///
+    /// for item in [1, 1, 2, 2, 1] {
+    ///     let _ = arr.push_back_if(item, |arr| arr.last() != Some(&item));
+    /// }
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 1]);
+    /// assert_eq!(arr.push_back_if(3, |_| false), Err(3));
+    /// ```
+    pub fn push_back_if<F: FnOnce(&Self) -> bool>(&mut self, item: T, cond: F) -> Result<(), T> {
+        if self.is_full() || !cond(self) {
+            return Err(item);
+        }
+
+        self.push_back(item)
+    }
+
     /// Attemps to pop the front item from this array.
     ///
     /// # Example
@@ -140,6 +517,68 @@ impl<T, const N: usize> ConstArray<T, N> {
         self.remove(0)
     }
 
+    /// Pops the first `K` items as an array, shifting the remaining items to the front once.
+    ///
+    /// Returns `None` without modifying the array if fewer than `K` items are initialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u8, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(arr.pop_front_array::<2>(), Some([1, 2]));
+    /// assert_eq!(arr.as_slice(), &[3, 4, 5]);
+    ///
+    /// assert_eq!(arr.pop_front_array::<4>(), None);
+    /// assert_eq!(arr.as_slice(), &[3, 4, 5]);
+    /// ```
+    pub const fn pop_front_array<const K: usize>(&mut self) -> Option<[T; K]> {
+        if self.len < K {
+            return None;
+        }
+
+        // SAFETY: The first `K` items are initialized, and are moved out before the shift below
+        let items = unsafe { core::ptr::read(self.buf.as_ptr().cast::<[T; K]>()) };
+
+        // SAFETY: We shift the remaining items over the moved out ones
+        unsafe {
+            let ptr = self.buf.as_mut_ptr();
+            core::ptr::copy(ptr.add(K), ptr, self.len - K);
+        }
+
+        self.len -= K;
+
+        Some(items)
+    }
+
+    /// Pops the last `K` items as an array, keeping their original order.
+    ///
+    /// Returns `None` without modifying the array if fewer than `K` items are initialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u8, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(arr.pop_back_n::<2>(), Some([4, 5]));
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    ///
+    /// assert_eq!(arr.pop_back_n::<4>(), None);
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub const fn pop_back_n<const K: usize>(&mut self) -> Option<[T; K]> {
+        if self.len < K {
+            return None;
+        }
+
+        self.len -= K;
+
+        // SAFETY: The last `K` items are initialized, and are no longer tracked by `len`
+        let items = unsafe { core::ptr::read(self.buf.as_ptr().add(self.len).cast::<[T; K]>()) };
+
+        Some(items)
+    }
+
     ///  Attempts to pop the last item from this array.
     ///
     /// # Example
@@ -167,6 +606,30 @@ impl<T, const N: usize> ConstArray<T, N> {
         }
     }
 
+    /// Removes the front item in O(1) by replacing it with the last item.
+    ///
+    /// This does **not** preserve the order of the remaining items. Use
+    /// [`ConstArray::pop_front()`] if the order matters.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 4]);
+    ///
+    /// assert_eq!(arr.swap_remove_front(), Some(1));
+    /// assert_eq!(arr.as_slice(), &[4, 2, 3]);
+    ///
+    /// assert_eq!(arr.swap_remove_front(), Some(4));
+    /// assert_eq!(arr.as_slice(), &[3, 2]);
+    /// ```
+    pub const fn swap_remove_front(&mut self) -> Option<T> {
+        if let Some(last) = self.len.checked_sub(1) {
+            self.as_mut_slice().swap(0, last);
+        }
+
+        self.pop_back()
+    }
+
     /// Removes the specified item from the array
     ///
     /// # Example
@@ -200,58 +663,415 @@ impl<T, const N: usize> ConstArray<T, N> {
         Some(item)
     }
 
-    /// Returns a slice that contains all initialized items
+    /// Removes the first item equal to `item`, shifting all following items to the left
     ///
     /// # Example
     /// ```rust
     /// # use const_array::ConstArray;
-    /// let mut arr = ConstArray::<u32, 16>::uninit();
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 2]);
     ///
-    /// arr.push_back(5);
-    /// arr.push_back(1);
-    /// arr.push_back(3);
+    /// assert_eq!(arr.remove_item(&2), Some(2));
+    /// assert_eq!(arr.as_slice(), &[1, 3, 2]);
     ///
-    /// assert_eq!(arr.as_slice(), &[5, 1, 3]);
-    /// # assert_eq!(arr.len(), 3);
+    /// assert_eq!(arr.remove_item(&5), None);
+    /// assert_eq!(arr.as_slice(), &[1, 3, 2]);
     /// ```
-    #[must_use]
-    pub const fn as_slice(&self) -> &[T] {
-        // SAFETY: We uphold the invariant that `self.len` is initialized
-        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast::<T>(), self.len) }
+    pub fn remove_item(&mut self, item: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let index = self.as_slice().iter().position(|x| x == item)?;
+
+        self.remove(index)
     }
 
-    /// Returns a mutable slice that contains all initialized items
+    /// Replaces the item at `index` with `value`, returning the old item without shifting.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
     ///
     /// # Example
     /// ```rust
     /// # use const_array::ConstArray;
-    /// let mut arr = ConstArray::from_array([0u32, 25]);
+    /// let mut arr = ConstArray::<u32, 4>::new([1, 2, 3]);
     ///
-    /// arr.as_mut_slice()[0] = 100;
-    ///
-    /// assert_eq!(arr.as_slice(), &[100, 25]);
-    /// # assert_eq!(arr.len(), 2);
-    /// # assert_eq!(arr.capacity(), 2);
+    /// assert_eq!(arr.replace(1, 20), 2);
+    /// assert_eq!(arr.as_slice(), &[1, 20, 3]);
+    /// # assert_eq!(arr.len(), 3);
     /// ```
-    #[must_use]
-    pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len) }
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        let len = self.len;
+        assert!(
+            index < len,
+            "replace index {index} is out of bounds for length {len}"
+        );
+
+        core::mem::replace(&mut self.as_mut_slice()[index], value)
     }
 
-    /// Constructs a [`ConstArray`] from its raw parts.
+    /// Passes each item to `f` by value, keeping the `Some` results in place and dropping the rest.
     ///
-    /// # Safety
-    /// It's required that the passed `len` is equivelant to the number of
-    /// valid entries in the array.
+    /// The relative order of the kept items is preserved. If `f` panics, the items that were
+    /// already kept remain in the array and the unprocessed items are dropped.
     ///
     /// # Example
     /// ```rust
     /// # use const_array::ConstArray;
-    /// # use core::mem::MaybeUninit;
-    /// let items = [
-    ///     MaybeUninit::new(1u32),
-    ///     MaybeUninit::new(2),
-    ///     MaybeUninit::new(3),
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 4, 5]);
+    ///
+    /// arr.filter_map_in_place(|x| (x % 2 == 1).then_some(x * 10));
+    ///
+    /// assert_eq!(arr.as_slice(), &[10, 30, 50]);
+    /// # assert_eq!(arr.capacity(), 5);
+    /// ```
+    pub fn filter_map_in_place<F: FnMut(T) -> Option<T>>(&mut self, mut f: F) {
+        let len = self.len;
+
+        // Items are owned by the guard while processing, so a panic can't double-drop them
+        self.len = 0;
+
+        let mut guard = CompactGuard {
+            arr: self,
+            read: 0,
+            write: 0,
+            len,
+        };
+
+        while guard.read < guard.len {
+            // SAFETY: Slots in `read..len` are initialized and not yet taken
+            let item = unsafe { guard.arr.buf[guard.read].assume_init_read() };
+            guard.read += 1;
+
+            if let Some(item) = f(item) {
+                // `write < read`, so this slot has already been moved out of
+                guard.arr.buf[guard.write] = MaybeUninit::new(item);
+                guard.write += 1;
+            }
+        }
+    }
+
+    /// Retains only the items within `range` for which `f` returns `true`.
+    ///
+    /// Items outside of `range` are always kept, and the array is compacted so that the
+    /// relative order of all kept items is preserved.
+    ///
+    /// # Panics
+    /// Panics if the range is decreasing or extends past the initialized items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 4, 5, 6]);
+    ///
+    /// arr.retain_range(1..5, |x| x % 2 == 1);
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 3, 5, 6]);
+    ///
+    /// arr.retain_range(2.., |_| false);
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 3]);
+    /// ```
+    pub fn retain_range<R: RangeBounds<usize>, F: FnMut(&T) -> bool>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        let range = resolve_range(&range, self.len);
+        let mut index = 0;
+
+        self.filter_map_in_place(|item| {
+            let keep = !range.contains(&index) || f(&item);
+            index += 1;
+
+            keep.then_some(item)
+        });
+    }
+
+    /// Removes and yields items from the front of the array while `f` returns `true`.
+    ///
+    /// Draining stops at the first item for which `f` returns `false`, which is kept. If the
+    /// iterator is dropped early, only the items that were yielded are removed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 10, 4]);
+    ///
+    /// let ready: Vec<_> = arr.drain_while(|x| *x < 5).collect();
+    ///
+    /// assert_eq!(ready, vec![1, 2, 3]);
+    /// assert_eq!(arr.as_slice(), &[10, 4]);
+    ///
+    /// assert_eq!(arr.drain_while(|_| true).next(), Some(10));
+    /// assert_eq!(arr.as_slice(), &[4]);
+    /// ```
+    pub fn drain_while<F: FnMut(&T) -> bool>(&mut self, f: F) -> impl Iterator<Item = T> {
+        iter::DrainWhile::new(self, f)
+    }
+
+    /// Returns an iterator that removes and yields the items for which `f` returns `true`.
+    ///
+    /// `f` may mutate the items it examines, and the kept items stay in their relative order.
+    /// If the iterator is dropped early, the items that were not examined yet are all kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 4, 5, 6]);
+    ///
+    /// let evens: Vec<_> = arr.extract_if(|x| *x % 2 == 0).collect();
+    ///
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(arr.as_slice(), &[1, 3, 5]);
+    ///
+    /// assert_eq!(arr.extract_if(|_| true).next(), Some(1));
+    /// assert_eq!(arr.as_slice(), &[3, 5]);
+    /// ```
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> impl Iterator<Item = T> {
+        iter::ExtractIf::new(self, f)
+    }
+
+    /// Returns a slice that contains all initialized items
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 16>::uninit();
+    ///
+    /// arr.push_back(5);
+    /// arr.push_back(1);
+    /// arr.push_back(3);
+    ///
+    /// assert_eq!(arr.as_slice(), &[5, 1, 3]);
+    /// # assert_eq!(arr.len(), 3);
+    /// ```
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: We uphold the invariant that `self.len` is initialized
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Returns a mutable slice that contains all initialized items
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([0u32, 25]);
+    ///
+    /// arr.as_mut_slice()[0] = 100;
+    ///
+    /// assert_eq!(arr.as_slice(), &[100, 25]);
+    /// # assert_eq!(arr.len(), 2);
+    /// # assert_eq!(arr.capacity(), 2);
+    /// ```
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Returns an iterator over the initialized items from back to front.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut stack = ConstArray::<u32, 8>::uninit();
+    ///
+    /// stack.push_back(1);
+    /// stack.push_back(2);
+    /// stack.push_back(3);
+    ///
+    /// assert_eq!(stack.iter_rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter_rev(&self) -> core::iter::Rev<core::slice::Iter<'_, T>> {
+        self.as_slice().iter().rev()
+    }
+
+    /// Returns an iterator over mutable references to the initialized items from back to front.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    ///
+    /// for (i, item) in arr.iter_rev_mut().enumerate() {
+    ///     *item += i as u32 * 10;
+    /// }
+    ///
+    /// assert_eq!(arr.as_slice(), &[21, 12, 3]);
+    /// ```
+    pub fn iter_rev_mut(&mut self) -> core::iter::Rev<core::slice::IterMut<'_, T>> {
+        self.as_mut_slice().iter_mut().rev()
+    }
+
+    /// Returns the initialized items as a pair of slices, in order.
+    ///
+    /// The items of a [`ConstArray`] are always contiguous, so the second slice is always
+    /// empty. This matches the interface of ring buffers, for code that is generic over both.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.as_slices(), (&[1, 2, 3][..], &[][..]));
+    /// ```
+    #[must_use]
+    pub const fn as_slices(&self) -> (&[T], &[T]) {
+        (self.as_slice(), &[])
+    }
+
+    /// Returns the initialized items as a single mutable slice.
+    ///
+    /// The items of a [`ConstArray`] are always contiguous, so this is free and doesn't move
+    /// anything. It matches the interface of ring buffers, for code that is generic over both.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 8>::new([3, 1, 2]);
+    ///
+    /// arr.make_contiguous().sort();
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub const fn make_contiguous(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+
+    /// Returns the initialized items and the spare capacity as two disjoint mutable slices
+    ///
+    /// As a `const fn`, this can also be used to fill an array while building a constant.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// # use core::mem::MaybeUninit;
+    /// let mut arr = ConstArray::<u32, 4>::new([1, 2]);
+    ///
+    /// let (init, spare) = arr.split_spare_mut();
+    /// init[0] = 10;
+    /// spare[0] = MaybeUninit::new(init.iter().sum());
+    ///
+    /// let (buf, len) = arr.into_raw_parts();
+    /// // SAFETY: One more item was initialized directly after the old items
+    /// let arr = unsafe { ConstArray::from_raw_parts(buf, len + 1) };
+    ///
+    /// assert_eq!(arr.as_slice(), &[10, 2, 12]);
+    /// ```
+    #[must_use]
+    pub const fn split_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let (init, spare) = self.buf.split_at_mut(self.len);
+
+        // SAFETY: We uphold the invariant that `self.len` is initialized
+        let init =
+            unsafe { core::slice::from_raw_parts_mut(init.as_mut_ptr().cast::<T>(), init.len()) };
+
+        (init, spare)
+    }
+
+    /// Returns a reference to `K` initialized items starting at `start` as an array, or `None`
+    /// if `start + K > len`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(arr.get_array::<2>(1), Some(&[2, 3]));
+    /// assert_eq!(arr.get_array::<2>(3), Some(&[4, 5]));
+    /// assert_eq!(arr.get_array::<2>(4), None);
+    /// assert_eq!(arr.get_array::<2>(usize::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn get_array<const K: usize>(&self, start: usize) -> Option<&[T; K]> {
+        match self.as_slice().split_at_checked(start) {
+            Some((_, rest)) => rest.first_chunk(),
+            None => None,
+        }
+    }
+
+    /// Splits off the first `K` initialized items as an array, or returns `None` if `len < K`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.split_first_chunk::<2>(), Some((&[1, 2], &[3][..])));
+    /// assert_eq!(arr.split_first_chunk::<3>(), Some((&[1, 2, 3], &[][..])));
+    /// assert_eq!(arr.split_first_chunk::<4>(), None);
+    /// ```
+    #[must_use]
+    pub const fn split_first_chunk<const K: usize>(&self) -> Option<(&[T; K], &[T])> {
+        self.as_slice().split_first_chunk()
+    }
+
+    /// Splits off the last `K` initialized items as an array, or returns `None` if `len < K`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.split_last_chunk::<2>(), Some((&[1][..], &[2, 3])));
+    /// assert_eq!(arr.split_last_chunk::<3>(), Some((&[][..], &[1, 2, 3])));
+    /// assert_eq!(arr.split_last_chunk::<4>(), None);
+    /// ```
+    #[must_use]
+    pub const fn split_last_chunk<const K: usize>(&self) -> Option<(&[T], &[T; K])> {
+        self.as_slice().split_last_chunk()
+    }
+
+    /// Returns a slice of the first `min(n, len)` initialized items
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(arr.head(2), &[1, 2]);
+    /// assert_eq!(arr.head(10), &[1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub const fn head(&self, n: usize) -> &[T] {
+        let n = if n < self.len { n } else { self.len };
+
+        self.as_slice().split_at(n).0
+    }
+
+    /// Returns a slice of the last `min(n, len)` initialized items
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(arr.tail(2), &[3, 4]);
+    /// assert_eq!(arr.tail(10), &[1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub const fn tail(&self, n: usize) -> &[T] {
+        let n = if n < self.len { n } else { self.len };
+
+        self.as_slice().split_at(self.len - n).1
+    }
+
+    /// Constructs a [`ConstArray`] from its raw parts.
+    ///
+    /// # Safety
+    /// It's required that the passed `len` is equivelant to the number of
+    /// valid entries in the array.
+    ///
+    /// In debug builds, a `len` larger than the capacity panics.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// # use core::mem::MaybeUninit;
+    /// let items = [
+    ///     MaybeUninit::new(1u32),
+    ///     MaybeUninit::new(2),
+    ///     MaybeUninit::new(3),
     ///     MaybeUninit::uninit(),
     /// ];
     ///
@@ -262,6 +1082,8 @@ impl<T, const N: usize> ConstArray<T, N> {
     /// # assert_eq!(arr.len(), 3);
     /// ```
     pub const unsafe fn from_raw_parts(array: [MaybeUninit<T>; N], len: usize) -> Self {
+        debug_assert!(len <= N, "length exceeds the capacity");
+
         Self { buf: array, len }
     }
 
@@ -288,63 +1110,1535 @@ impl<T, const N: usize> ConstArray<T, N> {
         buf
     }
 
-    /// Returns the number of used entries in the array.
+    /// Consumes the array without dropping any of its items, intentionally leaking them.
+    ///
+    /// This is the safe counterpart to the forget performed by [`ConstArray::into_raw_parts()`],
+    /// without handing back the storage. Leaking is safe, but any resources owned by the items,
+    /// such as heap memory or file handles, are never released.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// # use std::rc::Rc;
+    /// let shared = Rc::new(());
+    /// let arr = ConstArray::<_, 4>::new([Rc::clone(&shared), Rc::clone(&shared)]);
+    ///
+    /// arr.forget_contents();
+    ///
+    /// assert_eq!(Rc::strong_count(&shared), 3);
+    /// ```
+    pub const fn forget_contents(self) {
+        core::mem::forget(self);
+    }
+
+    /// Splits the array into two arrays with the capacities `L` and `R`, moving the items.
+    ///
+    /// The first `min(len, L)` items are moved into the left array, and any remaining items
+    /// are moved into the right array. A full array is therefore split exactly at `L`.
+    ///
+    /// `L + R` must be equal to `N`, which is checked at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::from_array([1u32, 2, 3, 4, 5]);
+    /// let (left, right) = arr.split_at_const::<2, 3>();
+    ///
+    /// assert_eq!(left.as_slice(), &[1, 2]);
+    /// assert_eq!(right.as_slice(), &[3, 4, 5]);
+    ///
+    /// let arr = ConstArray::<u32, 5>::new([1]);
+    /// let (left, right) = arr.split_at_const::<2, 3>();
+    ///
+    /// assert_eq!(left.as_slice(), &[1]);
+    /// assert!(right.is_empty());
+    /// ```
+    pub const fn split_at_const<const L: usize, const R: usize>(
+        self,
+    ) -> (ConstArray<T, L>, ConstArray<T, R>) {
+        const {
+            assert!(
+                L + R == N,
+                "split capacities must add up to the array capacity"
+            );
+        };
+
+        let (buf, len) = self.into_raw_parts();
+        let left_len = if len < L { len } else { L };
+
+        let mut left = ConstArray::<T, L>::uninit();
+        let mut right = ConstArray::<T, R>::uninit();
+
+        // SAFETY: The first `len` items of `buf` are initialized and ownership is moved out of them
+        // exactly once. Since `L + R == N`, the remainder always fits in the right array.
+        unsafe {
+            let src = buf.as_ptr();
+
+            core::ptr::copy_nonoverlapping(src, left.buf.as_mut_ptr(), left_len);
+            core::ptr::copy_nonoverlapping(
+                src.add(left_len),
+                right.buf.as_mut_ptr(),
+                len - left_len,
+            );
+        }
+
+        left.len = left_len;
+        right.len = len - left_len;
+
+        (left, right)
+    }
+
+    /// Maps each item with `f` into a new array with the capacity `M`.
+    ///
+    /// # Errors
+    /// If the array holds more than `M` items, this function will Err without calling `f`,
+    /// dropping the items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    /// let mapped = arr.map_collect::<_, 4, _>(|x| u64::from(x) * 2).unwrap();
+    ///
+    /// assert_eq!(mapped.as_slice(), &[2, 4, 6]);
+    /// assert_eq!(mapped.capacity(), 4);
+    ///
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    /// assert!(arr.map_collect::<_, 2, _>(|x| x).is_err());
+    /// ```
+    pub fn map_collect<U, const M: usize, F: FnMut(T) -> U>(
+        self,
+        f: F,
+    ) -> Result<ConstArray<U, M>, CapacityError<()>> {
+        if self.len > M {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut out = ConstArray::uninit();
+
+        for item in self.into_iter().map(f) {
+            out.buf[out.len] = MaybeUninit::new(item);
+            out.len += 1;
+        }
+
+        Ok(out)
+    }
+
+    /// Returns the number of used entries in the array.
+    ///
+    /// For the capacity, see [`ConstArray::capacity()`]
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 16>::uninit();
+    ///
+    /// arr.push_back(1);
+    /// arr.push_back(2);
+    /// arr.push_back(3);
+    ///
+    /// assert_eq!(arr.len(), 3);
+    /// # assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the total capacity of the array.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 16>::uninit();
+    ///
+    /// assert_eq!(arr.capacity(), 16);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns how full the array is, in integer thousandths of the capacity.
+    ///
+    /// An array with a capacity of zero always reports `0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static ARR: ConstArray<u32, 8> = ConstArray::new([1, 2, 3]);
+    /// const RATIO: usize = ARR.fill_ratio_permille();
+    ///
+    /// assert_eq!(RATIO, 375);
+    /// assert_eq!(ConstArray::<u32, 8>::uninit().fill_ratio_permille(), 0);
+    /// assert_eq!(ConstArray::from_array([1u32, 2]).fill_ratio_permille(), 1000);
+    /// assert_eq!(ConstArray::<u32, 0>::uninit().fill_ratio_permille(), 0);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn fill_ratio_permille(&self) -> usize {
+        // Widened so that `len * 1000` can't overflow, and the ratio never exceeds 1000
+        match (self.len as u128 * 1000).checked_div(N as u128) {
+            Some(ratio) => ratio as usize,
+            None => 0,
+        }
+    }
+
+    /// Checks that `additional` more items fit in the array.
+    ///
+    /// # Errors
+    /// If `len + additional` exceeds the capacity, or overflows a `usize`, this function will Err.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 4>::new([1, 2]);
+    ///
+    /// assert!(arr.ensure_capacity(2).is_ok());
+    /// assert!(arr.ensure_capacity(3).is_err());
+    /// assert!(arr.ensure_capacity(usize::MAX).is_err());
+    /// ```
+    pub const fn ensure_capacity(&self, additional: usize) -> Result<(), CapacityError<()>> {
+        match self.len.checked_add(additional) {
+            Some(total) if total <= N => Ok(()),
+            _ => Err(CapacityError::new(())),
+        }
+    }
+
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            // SAFETY: Since index < self.len(), only valid items can be retrieved
+            Some(unsafe { self.buf[index].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at `index`, where negative indices count from the back.
+    ///
+    /// An index of `-1` refers to the last item.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.get_signed(0), Some(&1));
+    /// assert_eq!(arr.get_signed(-1), Some(&3));
+    /// assert_eq!(arr.get_signed(-3), Some(&1));
+    /// assert_eq!(arr.get_signed(3), None);
+    /// assert_eq!(arr.get_signed(-4), None);
+    /// ```
+    #[must_use]
+    pub const fn get_signed(&self, index: isize) -> Option<&T> {
+        if index >= 0 {
+            return self.get(index.unsigned_abs());
+        }
+
+        match self.len.checked_sub(index.unsigned_abs()) {
+            Some(index) => self.get(index),
+            None => None,
+        }
+    }
+
+    pub const fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            // SAFETY: Since index < self.len(), only valid items can be retrieved
+            Some(unsafe { self.buf[index].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at `index`, pushing `f()` first if `index` is exactly `len`.
+    ///
+    /// Returns `None` without calling `f` if `index > len`, or if `index == len` and the
+    /// array is full.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 3>::new([1, 2]);
+    ///
+    /// assert_eq!(arr.get_or_push(0, || 10), Some(&mut 1));
+    /// assert_eq!(arr.get_or_push(2, || 30), Some(&mut 30));
+    /// assert_eq!(arr.get_or_push(3, || 40), None);
+    /// assert_eq!(arr.get_or_push(5, || 50), None);
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 30]);
+    /// ```
+    pub fn get_or_push<F: FnOnce() -> T>(&mut self, index: usize, f: F) -> Option<&mut T> {
+        if index == self.len && !self.is_full() {
+            self.buf[index] = MaybeUninit::new(f());
+            self.len += 1;
+        }
+
+        self.get_mut(index)
+    }
+
+    /// Counts the initialized items for which `pred` returns `true`.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::from_array([1u32, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(arr.count_by(|x| x % 2 == 1), 3);
+    /// assert_eq!(arr.count_by(|_| false), 0);
+    /// ```
+    pub fn count_by<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.as_slice().iter().filter(|item| pred(item)).count()
+    }
+
+    /// Lexicographically compares the initialized items against an array of any capacity.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// # use core::cmp::Ordering;
+    /// let a = ConstArray::<u32, 4>::new([1, 2, 3]);
+    /// let b = ConstArray::<u32, 8>::new([1, 2, 3]);
+    /// let c = ConstArray::<u32, 2>::new([1, 5]);
+    ///
+    /// assert_eq!(a.cmp_contents(&b), Ordering::Equal);
+    /// assert_eq!(a.cmp_contents(&c), Ordering::Less);
+    /// assert_eq!(c.cmp_contents(&b), Ordering::Greater);
+    /// ```
+    pub fn cmp_contents<const M: usize>(&self, other: &ConstArray<T, M>) -> Ordering
+    where
+        T: Ord,
+    {
+        self.as_slice().cmp(other.as_slice())
+    }
+
+    /// Copies the initialized items into a plain array, if exactly `M` items are initialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.copy_into_array::<3>(), Some([1, 2, 3]));
+    /// assert_eq!(arr.copy_into_array::<2>(), None);
+    /// assert_eq!(arr.copy_into_array::<4>(), None);
+    /// ```
+    #[must_use]
+    pub const fn copy_into_array<const M: usize>(&self) -> Option<[T; M]>
+    where
+        T: Copy,
+    {
+        if self.len != M {
+            return None;
+        }
+
+        match self.as_slice().first_chunk::<M>() {
+            Some(array) => Some(*array),
+            None => None,
+        }
+    }
+
+    /// Drops the current items, then moves the items of `array` into this array in place.
+    ///
+    /// `W` must not exceed `N`, which is checked at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 4>::new([1, 2, 3]);
+    ///
+    /// arr.reset_with([4, 5]);
+    ///
+    /// assert_eq!(arr.as_slice(), &[4, 5]);
+    /// # assert_eq!(arr.capacity(), 4);
+    /// ```
+    pub fn reset_with<const W: usize>(&mut self, array: [T; W]) {
+        const { assert!(W <= N, "array does not fit in the capacity") };
+
+        self.truncate(0);
+
+        let array = ManuallyDrop::new(array);
+
+        // SAFETY: `W <= N`, and the items are never dropped through `array` since it is wrapped
+        unsafe {
+            core::ptr::copy_nonoverlapping(array.as_ptr(), self.buf.as_mut_ptr().cast::<T>(), W);
+        }
+
+        self.len = W;
+    }
+
+    /// Returns references to the first and last items, which are the same item if `len == 1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 4>::new([1, 2, 3]);
+    /// assert_eq!(arr.ends(), Some((&1, &3)));
+    ///
+    /// let arr = ConstArray::<u32, 4>::new([7]);
+    /// assert_eq!(arr.ends(), Some((&7, &7)));
+    ///
+    /// assert_eq!(ConstArray::<u32, 4>::uninit().ends(), None);
+    /// ```
+    #[must_use]
+    pub const fn ends(&self) -> Option<(&T, &T)> {
+        match (self.as_slice().first(), self.as_slice().last()) {
+            (Some(first), Some(last)) => Some((first, last)),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the item that is `better` than all others, or `None` if empty.
+    ///
+    /// Items are scanned from front to back, and a candidate replaces the current best item
+    /// only if `better(candidate, current)` returns `true`, so ties keep the earliest index.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::from_array([3u32, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(arr.extreme_index_by(|a, b| a < b), Some(1));
+    /// assert_eq!(arr.extreme_index_by(|a, b| a > b), Some(4));
+    /// assert_eq!(ConstArray::<u32, 4>::uninit().extreme_index_by(|a, b| a < b), None);
+    /// ```
+    pub fn extreme_index_by<F: FnMut(&T, &T) -> bool>(&self, mut better: F) -> Option<usize> {
+        let items = self.as_slice();
+        let mut best = 0;
+
+        if items.is_empty() {
+            return None;
+        }
+
+        for (i, item) in items.iter().enumerate().skip(1) {
+            if better(item, &items[best]) {
+                best = i;
+            }
+        }
+
+        Some(best)
+    }
+
+    /// Returns `true` if every item is equal to the first according to `eq`, or if empty.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([0xff, 0xff, 0xff]);
+    /// assert!(arr.all_eq_by(|a, b| a == b));
+    ///
+    /// let arr = ConstArray::<u8, 8>::new([0xff, 0x00, 0xff]);
+    /// assert!(!arr.all_eq_by(|a, b| a == b));
+    ///
+    /// assert!(ConstArray::<u8, 8>::uninit().all_eq_by(|a, b| a == b));
+    /// ```
+    pub fn all_eq_by<F: FnMut(&T, &T) -> bool>(&self, mut eq: F) -> bool {
+        match self.as_slice().split_first() {
+            Some((first, rest)) => rest.iter().all(|item| eq(first, item)),
+            None => true,
+        }
+    }
+
+    /// Sorts the initialized items in place with a stable insertion sort.
+    ///
+    /// This runs in O(n²) time, but needs no allocation, which suits small arrays on targets
+    /// without `alloc`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 8>::new([5, 1, 4, 2, 3]);
+    ///
+    /// arr.insertion_sort();
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insertion_sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.insertion_sort_by(T::cmp);
+    }
+
+    /// Sorts the initialized items in place with a stable insertion sort, using `compare`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]);
+    ///
+    /// arr.insertion_sort_by(|a, b| a.0.cmp(&b.0));
+    ///
+    /// assert_eq!(arr.as_slice(), &[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    /// ```
+    pub fn insertion_sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let items = self.as_mut_slice();
+
+        for i in 1..items.len() {
+            let mut j = i;
+
+            // Only strictly greater items are passed, which keeps the sort stable
+            while j > 0 && compare(&items[j - 1], &items[j]) == Ordering::Greater {
+                items.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Returns an iterator over all overlapping windows of `W` initialized items, as arrays.
+    ///
+    /// If fewer than `W` items are initialized, the iterator yields nothing.
+    ///
+    /// # Panics
+    /// Panics if `W` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4]);
+    /// let mut windows = arr.array_windows::<2>();
+    ///
+    /// assert_eq!(windows.next(), Some(&[1, 2]));
+    /// assert_eq!(windows.next(), Some(&[2, 3]));
+    /// assert_eq!(windows.next(), Some(&[3, 4]));
+    /// assert_eq!(windows.next(), None);
+    ///
+    /// assert_eq!(arr.array_windows::<5>().count(), 0);
+    /// ```
+    pub fn array_windows<const W: usize>(&self) -> impl Iterator<Item = &[T; W]> + '_ {
+        // Every window has exactly `W` items, so the conversion can't fail
+        self.as_slice()
+            .windows(W)
+            .map(|window| window.try_into().unwrap())
+    }
+
+    /// Calls `f` with each initialized item and the item at the same index in `other`.
+    ///
+    /// When the lengths differ, only the overlapping `min(len, other.len())` items are visited,
+    /// and the rest are left untouched.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut acc = ConstArray::<i32, 8>::new([1, 2, 3, 4]);
+    ///
+    /// acc.zip_with_in_place(&[10, 20, 30], |a, b| *a += b);
+    ///
+    /// assert_eq!(acc.as_slice(), &[11, 22, 33, 4]);
+    /// ```
+    pub fn zip_with_in_place<F: FnMut(&mut T, &T)>(&mut self, other: &[T], mut f: F) {
+        for (item, other) in self.as_mut_slice().iter_mut().zip(other) {
+            f(item, other);
+        }
+    }
+
+    /// Consumes the array into an iterator that drops any items it did not yield.
+    ///
+    /// This is the same iterator as the [`IntoIterator`] implementation, named to make the
+    /// drop behavior explicit at the call site.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<String, 4>::new(["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// let mut iter = arr.into_iter_drop_rest();
+    ///
+    /// assert_eq!(iter.next().as_deref(), Some("a"));
+    /// // "b" and "c" are dropped here
+    /// drop(iter);
+    /// ```
+    pub fn into_iter_drop_rest(self) -> IntoIter<T, N> {
+        self.into_iter()
+    }
+
+    /// Appends items produced by `f` until the array is full.
+    ///
+    /// `f` is called with the index of the slot being filled. If `f` panics, the items that
+    /// were already appended remain in the array.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<usize, 5>::new([100, 200]);
+    ///
+    /// arr.fill_to_capacity_with(|i| i * 10);
+    ///
+    /// assert_eq!(arr.as_slice(), &[100, 200, 20, 30, 40]);
+    /// ```
+    pub fn fill_to_capacity_with<F: FnMut(usize) -> T>(&mut self, mut f: F) {
+        while self.len < N {
+            self.buf[self.len] = MaybeUninit::new(f(self.len));
+            self.len += 1;
+        }
+    }
+
+    /// Returns the number of leading items that are equal to the items of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new(*b"abcd");
+    ///
+    /// assert_eq!(arr.common_prefix_len(b"abcd"), 4);
+    /// assert_eq!(arr.common_prefix_len(b"abxy"), 2);
+    /// assert_eq!(arr.common_prefix_len(b"ab"), 2);
+    /// assert_eq!(arr.common_prefix_len(b"xyz"), 0);
+    /// ```
+    pub fn common_prefix_len(&self, other: &[T]) -> usize
+    where
+        T: PartialEq,
+    {
+        self.as_slice()
+            .iter()
+            .zip(other)
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Folds the initialized items into a `Copy` accumulator, starting from `init`.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let table = ConstArray::<u16, 8>::new([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(table.fold_copy(0u32, |acc, x| acc + u32::from(*x)), 10);
+    /// assert_eq!(ConstArray::<u16, 8>::uninit().fold_copy(7, |acc, _| acc + 1), 7);
+    /// ```
+    pub fn fold_copy<A: Copy, F: FnMut(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.as_slice().iter().fold(init, f)
+    }
+
+    /// Returns a checkpoint of the current length, for a later [`ConstArray::rollback_to()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut tokens = ConstArray::<u32, 8>::new([1, 2]);
+    /// let checkpoint = tokens.checkpoint();
+    ///
+    /// tokens.push_back(3);
+    /// tokens.push_back(4);
+    /// tokens.rollback_to(checkpoint);
+    ///
+    /// assert_eq!(tokens.as_slice(), &[1, 2]);
+    /// ```
+    #[must_use]
+    pub const fn checkpoint(&self) -> usize {
+        self.len
+    }
+
+    /// Drops all items pushed since `checkpoint`, restoring the length at that time.
+    ///
+    /// Rolling back only restores the previous contents if items were only pushed to the back
+    /// since the checkpoint was taken. If the array was reordered or items were removed in
+    /// between, this simply truncates the array to `checkpoint` items. It never leads to
+    /// undefined behavior, so it is safe to call.
+    ///
+    /// # Panics
+    /// Panics if `checkpoint > len`, as items removed since the checkpoint can't be restored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 8>::new([1]);
+    /// let checkpoint = arr.checkpoint();
+    ///
+    /// arr.push_back(2);
+    /// arr.rollback_to(checkpoint);
+    ///
+    /// assert_eq!(arr.as_slice(), &[1]);
+    /// ```
+    pub fn rollback_to(&mut self, checkpoint: usize) {
+        let len = self.len;
+        assert!(
+            checkpoint <= len,
+            "checkpoint {checkpoint} is past the length {len}"
+        );
+
+        self.truncate(checkpoint);
+    }
+
+    /// Drops the items past the first `len`, shortening the array to `len` items.
+    ///
+    /// # Panics
+    /// Panics if `len` exceeds the current length.
+    fn truncate(&mut self, len: usize) {
+        let items: *mut [T] = &raw mut self.as_mut_slice()[len..];
+
+        // The length is reset first, so a panicking destructor can't lead to a double drop
+        self.len = len;
+
+        // SAFETY: The items were initialized, and are no longer tracked by `len`
+        unsafe { core::ptr::drop_in_place(items) };
+    }
+
+    /// Copies `L` items starting at index `S` into a plain array, or returns `None` if
+    /// `S + L > len`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// static TABLE: ConstArray<u8, 8> = ConstArray::new([1, 2, 3, 4, 5]);
+    ///
+    /// const MIDDLE: Option<[u8; 3]> = TABLE.subarray_copy::<1, 3>();
+    /// const PAST_END: Option<[u8; 3]> = TABLE.subarray_copy::<3, 3>();
+    ///
+    /// assert_eq!(MIDDLE, Some([2, 3, 4]));
+    /// assert_eq!(PAST_END, None);
+    /// ```
+    #[must_use]
+    pub const fn subarray_copy<const S: usize, const L: usize>(&self) -> Option<[T; L]>
+    where
+        T: Copy,
+    {
+        let Some((_, rest)) = self.as_slice().split_at_checked(S) else {
+            return None;
+        };
+
+        match rest.first_chunk::<L>() {
+            Some(array) => Some(*array),
+            None => None,
+        }
+    }
+
+    /// Returns the number of maximal runs of equal consecutive items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// assert_eq!(ConstArray::from_array([1u8, 1, 2, 2, 2, 1]).run_count(), 3);
+    /// assert_eq!(ConstArray::from_array([7u8, 7, 7]).run_count(), 1);
+    /// assert_eq!(ConstArray::from_array([1u8, 2, 3]).run_count(), 3);
+    /// assert_eq!(ConstArray::<u8, 4>::uninit().run_count(), 0);
+    /// ```
+    pub fn run_count(&self) -> usize
+    where
+        T: PartialEq,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+
+        1 + self
+            .as_slice()
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count()
+    }
+
+    /// Removes every item from front to back, passing each to `f` by value.
+    ///
+    /// The array is empty afterwards. If `f` panics, the items that were not passed to it yet
+    /// are dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<String, 4>::new(["a".to_string(), "b".to_string()]);
+    /// let mut pool = Vec::new();
+    ///
+    /// arr.clear_with(|item| pool.push(item));
+    ///
+    /// assert!(arr.is_empty());
+    /// assert_eq!(pool, ["a", "b"]);
+    /// ```
+    pub fn clear_with<F: FnMut(T)>(&mut self, mut f: F) {
+        self.filter_map_in_place(|item| {
+            f(item);
+            None
+        });
+    }
+
+    /// Compares the subranges `a` and `b`, given as `(start, end)` pairs, item by item.
+    ///
+    /// Returns `false` if either range is decreasing or out of bounds, or if their lengths differ.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([1, 2, 3, 1, 2, 4]);
+    ///
+    /// assert!(arr.range_eq((0, 2), (3, 5), |a, b| a == b));
+    /// assert!(!arr.range_eq((0, 3), (3, 6), |a, b| a == b));
+    /// assert!(!arr.range_eq((0, 2), (3, 6), |a, b| a == b));
+    /// assert!(!arr.range_eq((0, 2), (5, 7), |a, b| a == b));
+    /// ```
+    pub fn range_eq<F: FnMut(&T, &T) -> bool>(
+        &self,
+        a: (usize, usize),
+        b: (usize, usize),
+        mut eq: F,
+    ) -> bool {
+        let items = self.as_slice();
+
+        match (items.get(a.0..a.1), items.get(b.0..b.1)) {
+            (Some(a), Some(b)) => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| eq(a, b)),
+            _ => false,
+        }
+    }
+
+    /// Moves the items into a plain array, filling the slots past `len` with `T::default()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 5>::new([1, 2, 3]);
+    ///
+    /// assert_eq!(arr.into_array_or_default(), [1, 2, 3, 0, 0]);
+    /// ```
+    pub fn into_array_or_default(self) -> [T; N]
+    where
+        T: Default,
+    {
+        let mut items = self.into_iter();
+
+        core::array::from_fn(|_| items.next().unwrap_or_default())
+    }
+
+    /// Inserts `item` into a sorted array at the position that keeps it sorted.
+    ///
+    /// The array must already be sorted in ascending order, otherwise the position is
+    /// unspecified. Equal items keep their insertion order, with `item` placed after them.
+    /// Returns the index at which `item` was inserted.
+    ///
+    /// # Errors
+    /// If the array is full, this function will Err and return the passed value
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u32, 5>::new([2, 4, 6]);
+    ///
+    /// assert_eq!(arr.insert_sorted(1), Ok(0));
+    /// assert_eq!(arr.insert_sorted(5), Ok(3));
+    /// assert_eq!(arr.as_slice(), &[1, 2, 4, 5, 6]);
+    ///
+    /// assert_eq!(arr.insert_sorted(3), Err(3));
+    /// ```
+    pub fn insert_sorted(&mut self, item: T) -> Result<usize, T>
+    where
+        T: Ord,
+    {
+        if self.is_full() {
+            return Err(item);
+        }
+
+        let index = self.as_slice().partition_point(|x| x <= &item);
+
+        // SAFETY: The array isn't full, so the items from `index` can be moved one to the right
+        unsafe {
+            let ptr = self.buf.as_mut_ptr().add(index);
+            core::ptr::copy(ptr, ptr.add(1), self.len - index);
+        }
+
+        self.buf[index] = MaybeUninit::new(item);
+        self.len += 1;
+
+        Ok(index)
+    }
+
+    /// Merges two sorted arrays into a sorted array with the capacity `R`, moving the items.
+    ///
+    /// Both arrays must already be sorted in ascending order. Equal items from `self` are
+    /// placed before those from `other`.
+    ///
+    /// # Errors
+    /// If the arrays hold more than `R` items in total, this function will Err, dropping all
+    /// items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let a = ConstArray::<u32, 4>::new([1, 3, 5]);
+    /// let b = ConstArray::<u32, 3>::new([2, 4, 6]);
+    ///
+    /// let merged = a.merge_sorted::<3, 8>(b).unwrap();
+    /// assert_eq!(merged.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let a = ConstArray::<u32, 4>::new([1, 3, 5]);
+    /// let b = ConstArray::<u32, 3>::new([2, 4, 6]);
+    /// assert!(a.merge_sorted::<3, 5>(b).is_err());
+    /// ```
+    pub fn merge_sorted<const M: usize, const R: usize>(
+        self,
+        other: ConstArray<T, M>,
+    ) -> Result<ConstArray<T, R>, CapacityError<()>>
+    where
+        T: Ord,
+    {
+        match self.len.checked_add(other.len) {
+            Some(total) if total <= R => {}
+            _ => return Err(CapacityError::new(())),
+        }
+
+        let mut a = self.into_iter().peekable();
+        let mut b = other.into_iter().peekable();
+        let mut out = ConstArray::uninit();
+
+        loop {
+            let item = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if y < x => b.next(),
+                (Some(_), _) => a.next(),
+                (None, _) => b.next(),
+            };
+
+            let Some(item) = item else {
+                return Ok(out);
+            };
+
+            out.buf[out.len] = MaybeUninit::new(item);
+            out.len += 1;
+        }
+    }
+
+    /// Moves the items into `K` rows of `C` items, if exactly `C * K` items are initialized.
+    ///
+    /// Otherwise the items are dropped and `None` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(arr.into_array_chunks::<3, 2>(), Some([[1, 2, 3], [4, 5, 6]]));
+    ///
+    /// let arr = ConstArray::<u32, 8>::new([1, 2, 3, 4, 5]);
+    /// assert_eq!(arr.into_array_chunks::<3, 2>(), None);
+    /// ```
+    pub fn into_array_chunks<const C: usize, const K: usize>(self) -> Option<[[T; C]; K]> {
+        if C.checked_mul(K) != Some(self.len) {
+            return None;
+        }
+
+        let (buf, _) = self.into_raw_parts();
+
+        // SAFETY: The first `C * K <= N` items are initialized, and are laid out exactly like
+        // `[[T; C]; K]`. Ownership moves out of `buf`, which never drops its items.
+        Some(unsafe { core::ptr::read(buf.as_ptr().cast::<[[T; C]; K]>()) })
+    }
+
+    /// Removes every item that is equal to an earlier item, keeping the first occurrences.
+    ///
+    /// Unlike removing consecutive duplicates only, this compares each item against all kept
+    /// items, which takes O(n²) time without allocating. The relative order of the kept items
+    /// is preserved.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 1, 3, 2]);
+    ///
+    /// arr.unique_in_place();
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn unique_in_place(&mut self)
+    where
+        T: PartialEq,
+    {
+        let len = self.len;
+
+        // Items are owned by the guard while processing, so a panic can't double-drop them
+        self.len = 0;
+
+        let mut guard = CompactGuard {
+            arr: self,
+            read: 0,
+            write: 0,
+            len,
+        };
+
+        while guard.read < guard.len {
+            // SAFETY: Slots in `read..len` are initialized and not yet taken
+            let item = unsafe { guard.arr.buf[guard.read].assume_init_read() };
+            guard.read += 1;
+
+            // SAFETY: Slots in `..write` hold the items kept so far
+            let kept = unsafe {
+                core::slice::from_raw_parts(guard.arr.buf.as_ptr().cast::<T>(), guard.write)
+            };
+
+            if !kept.contains(&item) {
+                // `write < read`, so this slot has already been moved out of
+                guard.arr.buf[guard.write] = MaybeUninit::new(item);
+                guard.write += 1;
+            }
+        }
+    }
+
+    /// Returns `true` if each item is `less` than the next one, or fewer than two items exist.
+    ///
+    /// Calling a function pointer is not allowed in a `const fn` on stable Rust, so unlike the
+    /// other query methods this can't be evaluated at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let lt = |a: &u32, b: &u32| a < b;
+    ///
+    /// assert!(ConstArray::from_array([1u32, 2, 5]).is_strictly_increasing_by(lt));
+    /// assert!(!ConstArray::from_array([1u32, 2, 2]).is_strictly_increasing_by(lt));
+    /// assert!(!ConstArray::from_array([3u32, 2]).is_strictly_increasing_by(lt));
+    /// assert!(ConstArray::from_array([7u32]).is_strictly_increasing_by(lt));
+    /// ```
+    pub fn is_strictly_increasing_by<F: FnMut(&T, &T) -> bool>(&self, mut less: F) -> bool {
+        self.as_slice()
+            .windows(2)
+            .all(|pair| less(&pair[0], &pair[1]))
+    }
+
+    /// Adds `scalar` to every initialized item in place.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut ints = ConstArray::<i32, 8>::new([1, -2, 3]);
+    /// ints.scalar_add_assign(10);
+    /// assert_eq!(ints.as_slice(), &[11, 8, 13]);
+    ///
+    /// let mut floats = ConstArray::<f32, 8>::new([0.5, 1.5]);
+    /// floats.scalar_add_assign(0.25);
+    /// assert_eq!(floats.as_slice(), &[0.75, 1.75]);
+    /// ```
+    pub fn scalar_add_assign(&mut self, scalar: T)
+    where
+        T: AddAssign + Copy,
+    {
+        for item in self.as_mut_slice() {
+            *item += scalar;
+        }
+    }
+
+    /// Multiplies every initialized item by `scalar` in place.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut ints = ConstArray::<i32, 8>::new([1, -2, 3]);
+    /// ints.scalar_mul_assign(3);
+    /// assert_eq!(ints.as_slice(), &[3, -6, 9]);
+    ///
+    /// let mut floats = ConstArray::<f64, 8>::new([0.5, 1.5]);
+    /// floats.scalar_mul_assign(2.0);
+    /// assert_eq!(floats.as_slice(), &[1.0, 3.0]);
+    /// ```
+    pub fn scalar_mul_assign(&mut self, scalar: T)
+    where
+        T: MulAssign + Copy,
+    {
+        for item in self.as_mut_slice() {
+            *item *= scalar;
+        }
+    }
+
+    /// Clones the initialized items into a new [`Vec`](alloc::vec::Vec).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<String, 4>::new(["a".to_string(), "b".to_string()]);
+    /// let vec = arr.to_vec();
+    ///
+    /// assert_eq!(vec, ["a", "b"]);
+    /// assert_eq!(arr.as_slice(), ["a", "b"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+
+    /// Returns an iterator over `chunk_size` initialized items at a time, without overlap.
+    ///
+    /// Items that don't fill a whole chunk are available through `remainder()`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u8, 8>::new([1, 2, 3, 4, 5]);
+    /// let mut chunks = arr.chunks_exact(2);
+    ///
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), &[5]);
+    /// ```
+    pub fn chunks_exact(&self, chunk_size: usize) -> core::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` mutable initialized items at a time, without
+    /// overlap.
+    ///
+    /// Items that don't fill a whole chunk are available through `into_remainder()`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::<u8, 8>::new([1, 2, 3, 4, 5]);
+    ///
+    /// for chunk in arr.chunks_exact_mut(2) {
+    ///     chunk.swap(0, 1);
+    /// }
+    ///
+    /// assert_eq!(arr.as_slice(), &[2, 1, 4, 3, 5]);
+    /// ```
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> core::slice::ChunksExactMut<'_, T> {
+        self.as_mut_slice().chunks_exact_mut(chunk_size)
+    }
+
+    /// Appends clones of the items in `slice`, evicting the oldest items from the front so that
+    /// the array holds the most recent `min(N, len + slice.len())` items.
+    ///
+    /// Returns the number of items evicted, counting items of `slice` that never fit.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut window = ConstArray::<u32, 4>::new([1, 2]);
+    ///
+    /// assert_eq!(window.feed(&[3, 4, 5]), 1);
+    /// assert_eq!(window.as_slice(), &[2, 3, 4, 5]);
+    ///
+    /// assert_eq!(window.feed(&[6, 7, 8, 9, 10, 11]), 6);
+    /// assert_eq!(window.as_slice(), &[8, 9, 10, 11]);
+    /// ```
+    pub fn feed(&mut self, slice: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let recent = &slice[slice.len().saturating_sub(N)..];
+        let len = self.len;
+        let evict = (len + recent.len()).saturating_sub(N);
+
+        // The oldest items are rotated to the back, where they are dropped
+        self.as_mut_slice().rotate_left(evict);
+        self.truncate(len - evict);
+
+        for item in recent {
+            // Enough items were evicted above for every push to succeed
+            let _ = self.push_back(item.clone());
+        }
+
+        evict + slice.len() - recent.len()
+    }
+
+    /// Truncates the array just before the first item equal to `delimiter`, dropping the
+    /// delimiter and every item after it.
+    ///
+    /// Returns `false` without modifying the array if no item equals `delimiter`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut line = ConstArray::<u8, 16>::new(*b"GET /\r\nHost");
+    ///
+    /// assert!(line.truncate_at(&b'\r'));
+    /// assert_eq!(line.as_slice(), b"GET /");
+    ///
+    /// assert!(!line.truncate_at(&b'\n'));
+    /// assert_eq!(line.as_slice(), b"GET /");
+    /// ```
+    pub fn truncate_at(&mut self, delimiter: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let Some(index) = self.iter().position(|item| item == delimiter) else {
+            return false;
+        };
+
+        self.truncate(index);
+
+        true
+    }
+
+    /// Returns `true` if both arrays have the same length and equal initialized items.
+    ///
+    /// The spare capacity is never read, so arrays built with [`ConstArray::from_raw_parts()`]
+    /// compare equal even if their uninitialized tails differ.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// # use core::mem::MaybeUninit;
+    /// let a = unsafe { ConstArray::from_raw_parts([1u8, 2, 0xAA].map(MaybeUninit::new), 2) };
+    /// let b = unsafe { ConstArray::from_raw_parts([1u8, 2, 0x55].map(MaybeUninit::new), 2) };
+    ///
+    /// assert!(a.buf_eq_initialized(&b));
+    /// assert!(!a.buf_eq_initialized(&ConstArray::new([1, 2, 0xAA])));
+    /// ```
+    #[must_use]
+    pub fn buf_eq_initialized(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice() == other.as_slice()
+    }
+
+    /// Counts the items at the start of the array that satisfy `f`, stopping at the first item
+    /// that doesn't.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let padded = ConstArray::<u8, 8>::new(*b"  hi ");
+    ///
+    /// assert_eq!(padded.leading_count(|b| *b == b' '), 2);
+    /// assert_eq!(padded.leading_count(|b| b.is_ascii()), 5);
+    /// assert_eq!(padded.leading_count(|b| *b == b'h'), 0);
+    /// ```
+    pub fn leading_count<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter().take_while(|item| f(item)).count()
+    }
+
+    /// Counts the items at the end of the array that satisfy `f`, stopping at the last item
+    /// that doesn't.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let padded = ConstArray::<u8, 8>::new(*b"  hi ");
+    ///
+    /// assert_eq!(padded.trailing_count(|b| *b == b' '), 1);
+    /// assert_eq!(padded.trailing_count(|b| b.is_ascii()), 5);
+    /// assert_eq!(padded.trailing_count(|b| *b == b'h'), 0);
+    /// ```
+    pub fn trailing_count<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter().rev().take_while(|item| f(item)).count()
+    }
+
+    /// Removes up to `K` items for which `f` returns `true`, moving them into a new array with a
+    /// capacity of `K`.
+    ///
+    /// The removed items keep their relative order in the returned array, and its length is the
+    /// number of items taken. The items kept in `self` also stay in their relative order. Once
+    /// `K` items were taken, the remaining items of `self` are left unexamined.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut arr = ConstArray::from_array([1u32, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(arr.take_matching::<2, _>(|x| *x % 2 == 0).as_slice(), &[2, 4]);
+    /// assert_eq!(arr.as_slice(), &[1, 3, 5, 6]);
+    ///
+    /// assert_eq!(arr.take_matching::<3, _>(|x| *x > 2).as_slice(), &[3, 5, 6]);
+    /// assert_eq!(arr.take_matching::<3, _>(|_| true).as_slice(), &[1]);
+    /// assert!(arr.is_empty());
+    /// ```
+    pub fn take_matching<const K: usize, F: FnMut(&T) -> bool>(
+        &mut self,
+        mut f: F,
+    ) -> ConstArray<T, K> {
+        let mut taken = ConstArray::uninit();
+
+        // `take` stops polling once `K` items were removed, so every push succeeds
+        for item in self.extract_if(|item| f(item)).take(K) {
+            let _ = taken.push_back(item);
+        }
+
+        taken
+    }
+
+    /// Converts a full array into a [`core::array::IntoIter`], or returns `None` and drops the
+    /// items if the array isn't full.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let full = ConstArray::from_array([1u32, 2, 3]);
+    /// let partial = ConstArray::<u32, 3>::new([1, 2]);
+    ///
+    /// assert!(full.into_full_array_iter().unwrap().eq([1, 2, 3]));
+    /// assert!(partial.into_full_array_iter().is_none());
+    /// ```
+    #[must_use]
+    pub fn into_full_array_iter(self) -> Option<core::array::IntoIter<T, N>> {
+        <[T; N]>::try_from(self).ok().map(IntoIterator::into_iter)
+    }
+
+    /// Reduces the items to a single value by repeatedly applying `f` to the accumulated value
+    /// and the next item.
+    ///
+    /// Returns `None` if the array is empty, and a clone of the only item if the array holds a
+    /// single item.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u32, 8>::new([3, 7, 5]);
+    ///
+    /// assert_eq!(arr.reduce(|a, b| *a.max(b)), Some(7));
+    /// assert_eq!(arr.reduce(|a, b| a + b), Some(15));
+    /// assert_eq!(ConstArray::<u32, 8>::new([4]).reduce(|a, b| a + b), Some(4));
+    /// assert_eq!(ConstArray::<u32, 8>::new([]).reduce(|a, b| a + b), None);
+    /// ```
+    pub fn reduce<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> Option<T>
+    where
+        T: Clone,
+    {
+        match self.as_slice() {
+            [] => None,
+            [only] => Some(only.clone()),
+            [first, second, rest @ ..] => Some(
+                rest.iter()
+                    .fold(f(first, second), |acc, item| f(&acc, item)),
+            ),
+        }
+    }
+
+    /// Replaces every item equal to `from` with a clone of `to`, returning the number of items
+    /// replaced.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut table = ConstArray::<u8, 8>::new([0, 3, 0, 0, 5]);
+    ///
+    /// assert_eq!(table.replace_all(&0, &9), 3);
+    /// assert_eq!(table.as_slice(), &[9, 3, 9, 9, 5]);
+    /// assert_eq!(table.replace_all(&0, &9), 0);
+    /// ```
+    pub fn replace_all(&mut self, from: &T, to: &T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+
+        for item in self.iter_mut().filter(|item| **item == *from) {
+            *item = to.clone();
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns an iterator over the indices of every item equal to `needle`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let buf = ConstArray::<u8, 8>::new(*b"a,b,,c");
+    ///
+    /// assert!(buf.indices_of(&b',').eq([1, 3, 4]));
+    /// assert_eq!(buf.indices_of(&b';').next(), None);
+    /// ```
+    pub fn indices_of<'a>(&'a self, needle: &'a T) -> impl Iterator<Item = usize> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .enumerate()
+            .filter(move |(_, item)| *item == needle)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the item with the largest key, or `None` if the array is empty.
+    ///
+    /// If several items have the largest key, the last one is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// struct Candidate {
+    ///     name: &'static str,
+    ///     score: u32,
+    /// }
+    ///
+    /// let candidates = ConstArray::<Candidate, 4>::new([
+    ///     Candidate { name: "a", score: 3 },
+    ///     Candidate { name: "b", score: 7 },
+    ///     Candidate { name: "c", score: 7 },
+    /// ]);
+    ///
+    /// assert_eq!(candidates.max_by_key(|c| c.score).unwrap().name, "c");
+    /// assert!(ConstArray::<Candidate, 4>::uninit().max_by_key(|c| c.score).is_none());
+    /// ```
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter().max_by_key(|item| f(item))
+    }
+
+    /// Returns the item with the smallest key, or `None` if the array is empty.
+    ///
+    /// If several items have the smallest key, the first one is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// struct Candidate {
+    ///     name: &'static str,
+    ///     score: u32,
+    /// }
+    ///
+    /// let candidates = ConstArray::<Candidate, 4>::new([
+    ///     Candidate { name: "a", score: 3 },
+    ///     Candidate { name: "b", score: 1 },
+    ///     Candidate { name: "c", score: 1 },
+    /// ]);
+    ///
+    /// assert_eq!(candidates.min_by_key(|c| c.score).unwrap().name, "b");
+    /// assert!(ConstArray::<Candidate, 4>::uninit().min_by_key(|c| c.score).is_none());
+    /// ```
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        self.iter().min_by_key(|item| f(item))
+    }
+
+    /// Drops the items in `start..end` in place, without shifting the items after them or
+    /// updating the length.
     ///
-    /// For the capacity, see [`ConstArray::capacity()`]
+    /// This is a building block for containers layered on top of this array, and is rarely the
+    /// right tool otherwise. Prefer [`ConstArray::truncate_at()`] or
+    /// [`ConstArray::retain_range()`] where they fit.
+    ///
+    /// # Safety
+    /// After this call the slots in `start..end` are uninitialized, but are still counted by
+    /// `len`. Before the array is used or dropped again, the caller must either write new items
+    /// into those slots, or move the items after them down and shrink the length, for example
+    /// through [`ConstArray::into_raw_parts()`] and [`ConstArray::from_raw_parts()`]. Otherwise
+    /// the dropped items are read or dropped a second time.
+    ///
+    /// If an item's destructor panics, the remaining items in the range are still dropped, and
+    /// the whole range must be treated as uninitialized.
+    ///
+    /// # Panics
+    /// Panics if `start > end` or `end > len`.
     ///
     /// # Example
     /// ```rust
     /// # use const_array::ConstArray;
-    /// let mut arr = ConstArray::<u32, 16>::uninit();
+    /// let mut arr = ConstArray::<String, 4>::new(["a", "b", "c"].map(String::from));
     ///
-    /// arr.push_back(1);
-    /// arr.push_back(2);
-    /// arr.push_back(3);
+    /// unsafe { arr.drop_range(1, 3) };
     ///
-    /// assert_eq!(arr.len(), 3);
-    /// # assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// // Shrink the length so that the dropped items aren't dropped again
+    /// let (buf, _) = arr.into_raw_parts();
+    /// let arr = unsafe { ConstArray::from_raw_parts(buf, 1) };
+    ///
+    /// assert_eq!(arr.as_slice(), &["a"]);
     /// ```
-    pub const fn len(&self) -> usize {
-        self.len
+    pub unsafe fn drop_range(&mut self, start: usize, end: usize) {
+        let items: *mut [T] = &raw mut self.as_mut_slice()[start..end];
+
+        // SAFETY: The items are initialized, and the caller upholds that they aren't used again
+        unsafe { core::ptr::drop_in_place(items) };
     }
 
-    /// Returns the total capacity of the array.
+    /// Appends the items of an iterator, failing once it yields more items than fit.
+    ///
+    /// # Errors
+    /// If the array runs out of capacity, this function will Err with the first item that did
+    /// not fit. Items appended before that stay in the array, and the rest of the iterator is
+    /// not consumed.
     ///
     /// # Example
     /// ```rust
     /// # use const_array::ConstArray;
-    /// let arr = ConstArray::<u32, 16>::uninit();
+    /// let mut arr = ConstArray::<u32, 4>::new([1]);
     ///
-    /// assert_eq!(arr.capacity(), 16);
+    /// assert!(arr.collect_into([2, 3]).is_ok());
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    ///
+    /// assert_eq!(arr.collect_into([4, 5, 6]).unwrap_err().element(), 5);
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4]);
     /// ```
-    pub const fn capacity(&self) -> usize {
-        N
-    }
+    pub fn collect_into<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<T>> {
+        for item in iter {
+            self.push_back(item).map_err(CapacityError::new)?;
+        }
 
-    pub const fn is_empty(&self) -> bool {
-        self.len == 0
+        Ok(())
     }
+}
 
-    pub const fn is_full(&self) -> bool {
-        self.len == N
-    }
+impl<T, const INNER: usize, const N: usize> ConstArray<ConstArray<T, INNER>, N> {
+    /// Concatenates the items of all inner arrays into a single array, moving them.
+    ///
+    /// # Errors
+    /// If the inner arrays hold more than `OUT` items in total, this function will Err,
+    /// dropping all items.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<_, 4>::new([
+    ///     ConstArray::<u32, 3>::new([1, 2]),
+    ///     ConstArray::new([]),
+    ///     ConstArray::new([3, 4, 5]),
+    /// ]);
+    ///
+    /// let flat = arr.clone().flatten::<8>().unwrap();
+    /// assert_eq!(flat.as_slice(), &[1, 2, 3, 4, 5]);
+    ///
+    /// assert!(arr.flatten::<4>().is_err());
+    /// ```
+    pub fn flatten<const OUT: usize>(self) -> Result<ConstArray<T, OUT>, CapacityError<()>> {
+        let total = self
+            .as_slice()
+            .iter()
+            .try_fold(0usize, |total, inner| total.checked_add(inner.len()));
 
-    pub const fn get(&self, index: usize) -> Option<&T> {
-        if index <= self.len() {
-            // SAFETY: Since index <= self.len(), only valid items can be retrieved
-            Some(unsafe { self.buf[index].assume_init_ref() })
-        } else {
-            None
+        match total {
+            Some(total) if total <= OUT => {}
+            _ => return Err(CapacityError::new(())),
         }
-    }
 
-    pub const fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index <= self.len() {
-            // SAFETY: Since index <= self.len(), only valid items can be retrieved
-            Some(unsafe { self.buf[index].assume_init_mut() })
-        } else {
-            None
+        let mut out = ConstArray::uninit();
+
+        for item in self.into_iter().flatten() {
+            out.buf[out.len] = MaybeUninit::new(item);
+            out.len += 1;
         }
+
+        Ok(out)
+    }
+}
+
+impl<T, const N: usize> ConstArray<Option<T>, N> {
+    /// Removes all `None` entries, shifting the `Some` entries forward in order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let mut slots = ConstArray::from_array([Some(1u32), None, Some(2), None, None, Some(3)]);
+    ///
+    /// slots.compact_options();
+    ///
+    /// assert_eq!(slots.as_slice(), &[Some(1), Some(2), Some(3)]);
+    /// ```
+    pub fn compact_options(&mut self) {
+        self.filter_map_in_place(|item| item.is_some().then_some(item));
     }
 }
 
@@ -363,8 +2657,10 @@ impl<T: Clone, const N: usize> Clone for ConstArray<T, N> {
     fn clone(&self) -> Self {
         let mut new_arr = ConstArray::<T, N>::uninit();
 
-        for i in 0..self.len() {
-            let _ = new_arr.push_back(new_arr.get(i).unwrap().clone());
+        // `len` is bumped after each write, so a panicking clone drops only the finished ones
+        for item in self.as_slice() {
+            new_arr.buf[new_arr.len] = MaybeUninit::new(item.clone());
+            new_arr.len += 1;
         }
 
         new_arr
@@ -442,5 +2738,668 @@ unsafe impl<T: Send, const N: usize> Send for ConstArray<T, N> {}
 
 unsafe impl<T: Sync, const N: usize> Sync for ConstArray<T, N> {}
 
+/// Converts `range` to a concrete range over `0..len`, panicking if it is out of bounds.
+fn resolve_range<R: RangeBounds<usize>>(range: &R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflowed"),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflowed"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "range start {start} is greater than range end {end}"
+    );
+    assert!(
+        end <= len,
+        "range end {end} is out of bounds for length {len}"
+    );
+
+    start..end
+}
+
+/// Restores a [`ConstArray`] being compacted in place, even when unwinding.
+///
+/// Items in `..write` are kept, and items in `read..len` are yet to be processed.
+struct CompactGuard<'a, T, const N: usize> {
+    arr: &'a mut ConstArray<T, N>,
+    read: usize,
+    write: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> Drop for CompactGuard<'_, T, N> {
+    fn drop(&mut self) {
+        for i in self.read..self.len {
+            // SAFETY: Unprocessed items are still initialized, and are never read again
+            unsafe { self.arr.buf[i].assume_init_drop() }
+        }
+
+        self.arr.len = self.write;
+    }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+    use std::rc::Rc;
+
+    /// Increments the shared counter when dropped
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn filter_map_in_place_panic_drops_once() {
+        let drops = Cell::new(0);
+        let mut arr =
+            ConstArray::<DropCounter, 5>::from_array(core::array::from_fn(|_| DropCounter(&drops)));
+        let mut seen = 0;
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            arr.filter_map_in_place(|item| {
+                seen += 1;
+
+                match seen {
+                    3 => panic!("boom"),
+                    _ if seen % 2 == 0 => None,
+                    _ => Some(item),
+                }
+            });
+        }));
+
+        assert!(result.is_err());
+        // The removed item, the item held during the panic, and the two unprocessed items
+        assert_eq!(drops.get(), 4);
+        assert_eq!(arr.len(), 1);
+
+        drop(arr);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn filled_panic_drops_clones() {
+        /// Panics on the third clone
+        struct Fragile<'a> {
+            clones: &'a Cell<usize>,
+            drop: DropCounter<'a>,
+        }
+
+        impl Clone for Fragile<'_> {
+            fn clone(&self) -> Self {
+                self.clones.set(self.clones.get() + 1);
+                assert!(self.clones.get() < 3, "clone failed");
+
+                Fragile {
+                    clones: self.clones,
+                    drop: DropCounter(self.drop.0),
+                }
+            }
+        }
+
+        let clones = Cell::new(0);
+        let drops = Cell::new(0);
+        let value = Fragile {
+            clones: &clones,
+            drop: DropCounter(&drops),
+        };
+
+        let result = catch_unwind(AssertUnwindSafe(|| ConstArray::<Fragile, 5>::filled(value)));
+
+        assert!(result.is_err());
+        // The two successful clones and the original value
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "length exceeds the capacity")]
+    fn from_raw_parts_len_past_capacity() {
+        let buf = [const { MaybeUninit::<u32>::uninit() }; 2];
+
+        // SAFETY: Not sound, but the debug assertion panics before the array is used
+        let arr = unsafe { ConstArray::from_raw_parts(buf, 3) };
+        arr.forget_contents();
+    }
+
+    #[test]
+    fn fill_to_capacity_with_panic_keeps_appended() {
+        let mut arr = ConstArray::<usize, 5>::new([0]);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            arr.fill_to_capacity_with(|i| if i < 3 { i } else { panic!("boom") });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(arr.as_slice(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn rollback_to_drops_pushed() {
+        let drops = Cell::new(0);
+        let mut arr = ConstArray::<DropCounter, 4>::new([DropCounter(&drops)]);
+        let checkpoint = arr.checkpoint();
+
+        let _ = arr.push_back(DropCounter(&drops));
+        let _ = arr.push_back(DropCounter(&drops));
+        arr.rollback_to(checkpoint);
+
+        assert_eq!(drops.get(), 2);
+        assert_eq!(arr.len(), 1);
+    }
+
+    #[test]
+    fn clear_with_panic_drops_rest_once() {
+        let drops = Cell::new(0);
+        let mut arr =
+            ConstArray::<DropCounter, 4>::from_array(core::array::from_fn(|_| DropCounter(&drops)));
+        let mut seen = 0;
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            arr.clear_with(|_item| {
+                seen += 1;
+                assert!(seen < 2, "boom");
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 4);
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn extract_if_partial_keeps_unexamined() {
+        let mut arr = ConstArray::from_array([1u32, 2, 3, 4, 5, 6]);
+        let mut iter = arr.extract_if(|x| {
+            *x *= 10;
+            *x % 20 == 0
+        });
+
+        assert_eq!(iter.next(), Some(20));
+        drop(iter);
+
+        // Only the first two items were examined, and so mutated
+        assert_eq!(arr.as_slice(), &[10, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn extract_if_drops_unconsumed_once() {
+        let drops = Cell::new(0);
+        let mut arr =
+            ConstArray::<DropCounter, 4>::from_array(core::array::from_fn(|_| DropCounter(&drops)));
+        let mut toggle = false;
+
+        let extracted = arr
+            .extract_if(|_| {
+                toggle = !toggle;
+                toggle
+            })
+            .count();
+
+        assert_eq!(extracted, 2);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(arr.len(), 2);
+
+        drop(arr);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn split_spare_mut_in_const() {
+        const ARR: ConstArray<u32, 4> = {
+            let mut arr = ConstArray::new([1, 2]);
+            let (init, spare) = arr.split_spare_mut();
+
+            init[0] = 10;
+            spare[0] = MaybeUninit::new(3);
+
+            let (buf, len) = arr.into_raw_parts();
+
+            // SAFETY: One more item was initialized directly after the old items
+            unsafe { ConstArray::from_raw_parts(buf, len + 1) }
+        };
+
+        assert_eq!(ARR.as_slice(), &[10, 2, 3]);
+    }
+
+    #[test]
+    fn unique_in_place_drops_duplicates() {
+        struct Keyed<'a> {
+            key: u32,
+            _drop: DropCounter<'a>,
+        }
+
+        impl PartialEq for Keyed<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut arr = ConstArray::<Keyed, 8>::new([1, 2, 1, 3, 2, 1].map(|key| Keyed {
+            key,
+            _drop: DropCounter(&drops),
+        }));
+
+        arr.unique_in_place();
+
+        assert_eq!(drops.get(), 3);
+        assert_eq!(
+            arr.iter().map(|item| item.key).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn forget_contents_runs_no_drops() {
+        let drops = Cell::new(0);
+        let arr = ConstArray::<DropCounter, 4>::new([DropCounter(&drops), DropCounter(&drops)]);
+
+        arr.forget_contents();
+
+        assert_eq!(drops.get(), 0);
+    }
+
+    #[test]
+    fn reset_with_drops_old_contents() {
+        let old = Cell::new(0);
+        let new = Cell::new(0);
+        let mut arr = ConstArray::<DropCounter, 4>::new([
+            DropCounter(&old),
+            DropCounter(&old),
+            DropCounter(&old),
+        ]);
+
+        arr.reset_with([DropCounter(&new)]);
+
+        assert_eq!(old.get(), 3);
+        assert_eq!(new.get(), 0);
+        assert_eq!(arr.len(), 1);
+
+        drop(arr);
+        assert_eq!(new.get(), 1);
+    }
+
+    #[test]
+    fn into_iter_interleaved_drops_remainder() {
+        let drops = Cell::new(0);
+        let arr =
+            ConstArray::<DropCounter, 6>::from_array(core::array::from_fn(|_| DropCounter(&drops)));
+        let mut iter = arr.into_iter();
+
+        drop(iter.next());
+        drop(iter.next_back());
+        drop(iter.next());
+        drop(iter.next_back());
+
+        assert_eq!(drops.get(), 4);
+        assert_eq!(iter.len(), 2);
+
+        drop(iter);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn into_iter_drop_rest_partial() {
+        let drops = Cell::new(0);
+        let arr = ConstArray::<DropCounter, 8>::new([
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+        ]);
+        let mut iter = arr.into_iter_drop_rest();
+
+        let first = iter.next();
+        drop(iter);
+
+        assert_eq!(drops.get(), 2);
+
+        drop(first);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn into_iter_meets_in_the_middle() {
+        let mut iter = ConstArray::<u32, 4>::new([1, 2, 3]).into_iter();
+
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn insertion_sort_orders() {
+        let mut sorted = ConstArray::from_array([1u32, 2, 3, 4, 5]);
+        sorted.insertion_sort();
+        assert_eq!(sorted.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let mut reversed = ConstArray::from_array([5u32, 4, 3, 2, 1]);
+        reversed.insertion_sort();
+        assert_eq!(reversed.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let mut descending = ConstArray::<u32, 8>::new([2, 3, 1]);
+        descending.insertion_sort_by(|a, b| b.cmp(a));
+        assert_eq!(descending.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn insert_sorted_positions() {
+        let mut arr = ConstArray::<u32, 5>::new([10, 20]);
+
+        assert_eq!(arr.insert_sorted(5), Ok(0));
+        assert_eq!(arr.insert_sorted(15), Ok(2));
+        assert_eq!(arr.insert_sorted(25), Ok(4));
+        assert_eq!(arr.as_slice(), &[5, 10, 15, 20, 25]);
+        assert_eq!(arr.insert_sorted(1), Err(1));
+    }
+
+    #[test]
+    fn size_covers_fields() {
+        const _: () = assert!(ConstArray::<u8, 3>::SIZE >= 3 + size_of::<usize>());
+        const _: () = assert!(ConstArray::<u64, 4>::SIZE >= 4 * 8 + size_of::<usize>());
+        const _: () = assert!(ConstArray::<u32, 0>::SIZE == size_of::<usize>());
+    }
+
+    #[cfg(all(debug_assertions, feature = "debug-canary"))]
+    #[test]
+    fn canary_fills_spare_capacity() {
+        let mut arr = ConstArray::<u32, 4>::new([1]);
+        let (_, spare) = arr.split_spare_mut();
+
+        // SAFETY: The spare capacity was filled with the canary byte on construction
+        let bytes =
+            unsafe { core::slice::from_raw_parts(spare.as_ptr().cast::<u8>(), size_of_val(spare)) };
+
+        assert_eq!(bytes.len(), 12);
+        assert!(bytes.iter().all(|&byte| byte == CANARY));
+        assert_eq!(arr.as_slice(), &[1]);
+    }
+
+    #[cfg(feature = "repr-c")]
+    #[test]
+    fn repr_c_layout() {
+        use core::mem::{align_of, offset_of};
+
+        // `len` follows the buffer, padded up to its own alignment
+        const BYTES_LEN: usize = size_of::<[u8; 3]>().next_multiple_of(align_of::<usize>());
+        const WORDS_LEN: usize = size_of::<[u16; 5]>().next_multiple_of(align_of::<usize>());
+
+        const _: () = assert!(offset_of!(ConstArray<u8, 3>, len) == BYTES_LEN);
+        const _: () = assert!(ConstArray::<u8, 3>::SIZE == BYTES_LEN + size_of::<usize>());
+        const _: () = assert!(offset_of!(ConstArray<u16, 5>, buf) == 0);
+        const _: () = assert!(offset_of!(ConstArray<u16, 5>, len) == WORDS_LEN);
+        const _: () = assert!(ConstArray::<u16, 5>::SIZE == WORDS_LEN + size_of::<usize>());
+    }
+
+    /// The length can't overflow, as it never exceeds `N <= usize::MAX`. Zero-sized items allow
+    /// an array with `N == usize::MAX` to exist without allocating anything.
+    mod max_capacity {
+        use super::*;
+
+        type Huge = ConstArray<(), { usize::MAX }>;
+
+        fn almost_full() -> Huge {
+            let buf = [const { MaybeUninit::uninit() }; usize::MAX];
+
+            // SAFETY: `()` is zero-sized, so every slot is trivially initialized
+            unsafe { Huge::from_raw_parts(buf, usize::MAX - 1) }
+        }
+
+        #[test]
+        fn push_back_to_max() {
+            let mut arr = almost_full();
+
+            assert_eq!(arr.push_back(()), Ok(()));
+            assert_eq!(arr.len(), usize::MAX);
+            assert!(arr.is_full());
+            assert_eq!(arr.push_back(()), Err(()));
+
+            // Dropping would iterate over `usize::MAX` slots
+            arr.forget_contents();
+        }
+
+        #[test]
+        fn push_front_to_max() {
+            let mut arr = almost_full();
+
+            assert_eq!(arr.push_front(()), Ok(()));
+            assert_eq!(arr.len(), usize::MAX);
+            assert_eq!(arr.push_front(()), Err(()));
+
+            arr.forget_contents();
+        }
+
+        #[test]
+        fn fill_ratio_permille_large_len() {
+            let buf = [const { MaybeUninit::uninit() }; usize::MAX];
+
+            // SAFETY: `()` is zero-sized, so every slot is trivially initialized
+            let half = unsafe { Huge::from_raw_parts(buf, usize::MAX / 2) };
+            assert_eq!(half.fill_ratio_permille(), 499);
+            half.forget_contents();
+
+            let full = almost_full();
+            assert_eq!(full.fill_ratio_permille(), 999);
+            full.forget_contents();
+        }
+    }
+
+    #[test]
+    fn feed_past_capacity_keeps_recent() {
+        let mut window = ConstArray::<Rc<u32>, 3>::new([Rc::new(1), Rc::new(2)]);
+        let fed: Vec<Rc<u32>> = (3..=7).map(Rc::new).collect();
+
+        assert_eq!(window.feed(&fed), 4);
+        assert_eq!(window.iter().map(|i| **i).collect::<Vec<_>>(), [5, 6, 7]);
+        assert_eq!(Rc::strong_count(&fed[0]), 1);
+        assert_eq!(Rc::strong_count(&fed[4]), 2);
+    }
+
+    #[test]
+    fn truncate_at_drops_tail() {
+        let shared = Rc::new(0);
+        let mut arr = ConstArray::<Rc<u32>, 4>::from_array([
+            Rc::new(1),
+            Rc::clone(&shared),
+            Rc::clone(&shared),
+            Rc::new(2),
+        ]);
+
+        assert!(arr.truncate_at(&shared));
+        assert_eq!(arr.as_slice(), &[Rc::new(1)]);
+        assert_eq!(Rc::strong_count(&shared), 1);
+
+        assert!(!arr.truncate_at(&shared));
+        assert_eq!(arr.len(), 1);
+    }
+
+    #[test]
+    fn into_full_array_iter_drops_partial() {
+        let drops = Cell::new(0);
+        let partial = ConstArray::<DropCounter, 3>::new([DropCounter(&drops), DropCounter(&drops)]);
+
+        assert!(partial.into_full_array_iter().is_none());
+        assert_eq!(drops.get(), 2);
+
+        let full = ConstArray::from_array([DropCounter(&drops), DropCounter(&drops)]);
+        let mut iter = full.into_full_array_iter().unwrap();
+
+        drop(iter.next());
+        assert_eq!(drops.get(), 3);
+
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn clone_copies_from_source() {
+        let arr = ConstArray::<Rc<u32>, 4>::new([Rc::new(1), Rc::new(2)]);
+        let cloned = arr.clone();
+
+        assert_eq!(cloned, arr);
+        assert!(Rc::ptr_eq(&cloned[0], &arr[0]));
+        assert_eq!(Rc::strong_count(&arr[1]), 2);
+    }
+
+    #[test]
+    fn clone_matches_len_and_contents() {
+        let arr = ConstArray::<String, 8>::new([
+            String::from("a"),
+            String::from("bc"),
+            String::from("def"),
+        ]);
+        let cloned = arr.clone();
+
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(cloned.as_slice(), arr.as_slice());
+
+        let empty = ConstArray::<String, 8>::uninit().clone();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn drop_range_drops_once() {
+        let drops = Cell::new(0);
+        let mut arr = ConstArray::<DropCounter, 4>::new([
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+        ]);
+
+        unsafe { arr.drop_range(0, 2) };
+        assert_eq!(drops.get(), 2);
+
+        // Move the last item down over the dropped ones
+        let (mut buf, _) = arr.into_raw_parts();
+        buf.swap(0, 2);
+        let arr = unsafe { ConstArray::from_raw_parts(buf, 1) };
+
+        drop(arr);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn with_len_default_without_item_default() {
+        struct NoDefault;
+
+        let arr = WithLen::<NoDefault, 4>::default();
+        assert!(arr.0.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn with_len_field_indices_and_bytes() {
+        use serde::Deserialize;
+        use serde::de::value::MapDeserializer;
+        use serde_json::{Value, json};
+
+        let values = || [json!(2), json!([1, 2])];
+
+        let indices =
+            MapDeserializer::<_, serde_json::Error>::new([0u64, 1].into_iter().zip(values()));
+        let arr = WithLen::<u32, 4>::deserialize(indices).unwrap();
+        assert_eq!(arr.0.as_slice(), &[1, 2]);
+
+        let names: [&[u8]; 2] = [b"len", b"data"];
+        let bytes = MapDeserializer::<_, serde_json::Error>::new(names.into_iter().zip(values()));
+        let arr = WithLen::<u32, 4>::deserialize(bytes).unwrap();
+        assert_eq!(arr.0.as_slice(), &[1, 2]);
+
+        let unknown =
+            MapDeserializer::<_, serde_json::Error>::new([(2u64, Value::Null)].into_iter());
+        assert!(WithLen::<u32, 4>::deserialize(unknown).is_err());
+    }
+
+    #[test]
+    fn bitmask_round_trip() {
+        for bits in [0, 1, 0b1010_0101, u64::MAX] {
+            let flags = ConstArray::<bool, 64>::from_bitmask_u64(bits, 64).unwrap();
+            assert_eq!(flags.to_bitmask_u64(), bits);
+        }
+
+        let flags = ConstArray::<bool, 16>::from_bitmask_u64(u64::MAX, 3).unwrap();
+        assert_eq!(flags.as_slice(), &[true; 3]);
+        assert_eq!(flags.to_bitmask_u64(), 0b111);
+    }
+
+    mod zero_capacity {
+        use super::*;
+
+        type Empty = ConstArray<u32, 0>;
+
+        #[test]
+        fn capacity_and_len() {
+            let arr = Empty::uninit();
+
+            assert_eq!(arr.capacity(), 0);
+            assert_eq!(arr.len(), 0);
+            assert!(arr.is_empty());
+            assert!(arr.is_full());
+            assert_eq!(arr.fill_ratio_permille(), 0);
+        }
+
+        #[test]
+        fn push_always_errs() {
+            let mut arr = Empty::new([]);
+
+            assert_eq!(arr.push_back(1), Err(1));
+            assert_eq!(arr.push_front(2), Err(2));
+            assert_eq!(arr.len(), 0);
+        }
+
+        #[test]
+        fn pop_always_none() {
+            let mut arr = Empty::default();
+
+            assert_eq!(arr.pop_back(), None);
+            assert_eq!(arr.pop_front(), None);
+            assert_eq!(arr.remove(0), None);
+        }
+
+        #[test]
+        fn access_is_empty() {
+            let mut arr = Empty::from_array([]);
+
+            assert_eq!(arr.get(0), None);
+            assert_eq!(arr.get_mut(0), None);
+            assert_eq!(arr.as_slice(), &[] as &[u32]);
+            assert_eq!(arr.as_mut_slice(), &mut [] as &mut [u32]);
+            assert!(arr.as_slice().as_ptr().is_aligned());
+            assert_eq!(arr.head(1), &[] as &[u32]);
+            assert_eq!(arr.tail(1), &[] as &[u32]);
+        }
+
+        #[test]
+        fn construction_from_iterators() {
+            assert!(Empty::from_exact_iter([]).is_ok());
+            assert!(Empty::from_exact_iter([1]).is_err());
+            assert!(Empty::try_from_iter([]).is_ok());
+            assert_eq!(Empty::try_from_iter([1]).unwrap_err().element(), 1);
+        }
+
+        #[test]
+        fn traits() {
+            let arr = Empty::uninit();
+
+            assert_eq!(arr.clone(), arr);
+            assert_eq!(arr.cmp(&Empty::uninit()), Ordering::Equal);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::ConstArray;
+
+/// Generates the const numeric helpers for an unsigned integer element type
+macro_rules! num_impl {
+    ($int:ty, $sum:ident, $values:expr, $expected:expr) => {
+        impl<const N: usize> ConstArray<$int, N> {
+            /// Sums the initialized items, wrapping around on overflow.
+            ///
+            /// # Example
+            /// ```rust
+            /// # use const_array::ConstArray;
+            #[doc = concat!("static ARR: ConstArray<", stringify!($int), ", 8> = ConstArray::new(", stringify!($values), ");")]
+            #[doc = concat!("const SUM: ", stringify!($int), " = ARR.", stringify!($sum), "();")]
+            ///
+            #[doc = concat!("assert_eq!(SUM, ", stringify!($expected), ");")]
+            #[doc = concat!("assert_eq!(ConstArray::<", stringify!($int), ", 8>::uninit().", stringify!($sum), "(), 0);")]
+            /// ```
+            #[must_use]
+            pub const fn $sum(&self) -> $int {
+                let items = self.as_slice();
+                let mut sum: $int = 0;
+                let mut i = 0;
+
+                while i < items.len() {
+                    sum = sum.wrapping_add(items[i]);
+                    i += 1;
+                }
+
+                sum
+            }
+        }
+    };
+}
+
+num_impl!(u8, sum_u8, [200, 50, 10], 4);
+num_impl!(u16, sum_u16, [u16::MAX, 2, 3], 4);
+num_impl!(u32, sum_u32, [1, 2, 3], 6);
+num_impl!(u64, sum_u64, [u64::MAX, 5], 4);
@@ -0,0 +1,68 @@
+use bytemuck::Pod;
+
+use crate::ConstArray;
+
+impl<T: Pod, const N: usize> ConstArray<T, N> {
+    /// Reinterprets the initialized items as a byte slice, without copying.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// let arr = ConstArray::<u16, 4>::new([0x0102, 0x0304]);
+    ///
+    /// assert_eq!(arr.as_bytes(), &[0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat()[..]);
+    /// ```
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Creates an array by reading items from a byte slice.
+    ///
+    /// Returns `None` if the length of `bytes` is not a multiple of `size_of::<T>()`, or if
+    /// it holds more than `N` items. The bytes don't need to be aligned for `T`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use const_array::ConstArray;
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// #[repr(C)]
+    /// struct Point {
+    ///     x: u16,
+    ///     y: u16,
+    /// }
+    ///
+    /// // SAFETY: `Point` is `repr(C)`, has no padding, and any bit pattern is valid
+    /// unsafe impl bytemuck::Zeroable for Point {}
+    /// unsafe impl bytemuck::Pod for Point {}
+    ///
+    /// let points = ConstArray::<Point, 4>::new([Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+    /// let copy = ConstArray::<Point, 4>::try_from_bytes(points.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(copy.as_slice(), points.as_slice());
+    /// assert!(ConstArray::<Point, 4>::try_from_bytes(&[0; 3]).is_none());
+    /// assert!(ConstArray::<Point, 1>::try_from_bytes(points.as_bytes()).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+        let size = size_of::<T>();
+
+        if size == 0 {
+            return bytes.is_empty().then(Self::uninit);
+        }
+
+        // `usize::is_multiple_of` would raise the minimum supported Rust version to 1.87
+        #[allow(clippy::manual_is_multiple_of)]
+        if bytes.len() % size != 0 || bytes.len() / size > N {
+            return None;
+        }
+
+        let mut arr = Self::uninit();
+
+        for chunk in bytes.chunks_exact(size) {
+            let _ = arr.push_back(bytemuck::pod_read_unaligned(chunk));
+        }
+
+        Some(arr)
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::ConstArray;
+
+/// Serializes the initialized items as a sequence.
+///
+/// # Example
+/// ```rust
+/// # use const_array::ConstArray;
+/// let arr = ConstArray::<u32, 8>::new([1, 2, 3]);
+/// let json = serde_json::to_string(&arr).unwrap();
+///
+/// assert_eq!(json, "[1,2,3]");
+/// assert_eq!(serde_json::from_str::<ConstArray<u32, 8>>(&json).unwrap(), arr);
+/// assert!(serde_json::from_str::<ConstArray<u32, 2>>(&json).is_err());
+/// ```
+impl<T: Serialize, const N: usize> Serialize for ConstArray<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Deserializes a sequence of at most `N` items.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ConstArray<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ArrayVisitor(PhantomData))
+    }
+}
+
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = ConstArray<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at most {N} items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = ConstArray::uninit();
+
+        while let Some(item) = seq.next_element()? {
+            if arr.push_back(item).is_err() {
+                return Err(de::Error::invalid_length(N.saturating_add(1), &self));
+            }
+        }
+
+        Ok(arr)
+    }
+}
+
+/// Serializes a [`ConstArray`] as a struct holding both its length and items.
+///
+/// The plain representation of a [`ConstArray`] is a sequence of its items. This wrapper
+/// instead uses `{ "len": n, "data": [...] }`, so that the length round-trips explicitly and
+/// deserializing data that doesn't match its length is an error.
+///
+/// # Example
+/// ```rust
+/// # use const_array::{ConstArray, WithLen};
+/// let arr = WithLen(ConstArray::<u32, 8>::new([1, 2, 3]));
+/// let json = serde_json::to_string(&arr).unwrap();
+///
+/// assert_eq!(json, r#"{"len":3,"data":[1,2,3]}"#);
+/// assert_eq!(serde_json::from_str::<WithLen<u32, 8>>(&json).unwrap().0, arr.0);
+///
+/// assert!(serde_json::from_str::<WithLen<u32, 8>>(r#"{"len":2,"data":[1,2,3]}"#).is_err());
+/// assert!(serde_json::from_str::<WithLen<u32, 2>>(&json).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WithLen<T, const N: usize>(pub ConstArray<T, N>);
+
+// Written by hand, as deriving it would require `T: Default`
+impl<T, const N: usize> Default for WithLen<T, N> {
+    fn default() -> Self {
+        Self(ConstArray::uninit())
+    }
+}
+
+const FIELDS: &[&str] = &["len", "data"];
+
+impl<T: Serialize, const N: usize> Serialize for WithLen<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("WithLen", 2)?;
+
+        state.serialize_field("len", &self.0.len())?;
+        state.serialize_field("data", &self.0)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for WithLen<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("WithLen", FIELDS, WithLenVisitor(PhantomData))
+    }
+}
+
+struct WithLenVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> WithLenVisitor<T, N> {
+    /// Checks that the deserialized items match the serialized length
+    fn check<E: de::Error>(len: usize, data: ConstArray<T, N>) -> Result<WithLen<T, N>, E> {
+        if data.len() == len {
+            Ok(WithLen(data))
+        } else {
+            Err(E::invalid_length(
+                data.len(),
+                &"as many items as the serialized length",
+            ))
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for WithLenVisitor<T, N> {
+    type Value = WithLen<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a struct with a length and at most {N} items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let len = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let data = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Self::check(len, data)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut len = None;
+        let mut data = None;
+
+        while let Some(key) = map.next_key::<Field>()? {
+            match key {
+                Field::Len if len.is_some() => return Err(de::Error::duplicate_field("len")),
+                Field::Data if data.is_some() => return Err(de::Error::duplicate_field("data")),
+                Field::Len => len = Some(map.next_value()?),
+                Field::Data => data = Some(map.next_value()?),
+            }
+        }
+
+        let len = len.ok_or_else(|| de::Error::missing_field("len"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+        Self::check(len, data)
+    }
+}
+
+/// The fields of the [`WithLen`] representation
+enum Field {
+    Len,
+    Data,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`len` or `data`")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Field, E> {
+        match value {
+            0 => Ok(Field::Len),
+            1 => Ok(Field::Data),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &"field index 0 <= i < 2",
+            )),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+        match value {
+            "len" => Ok(Field::Len),
+            "data" => Ok(Field::Data),
+            _ => Err(de::Error::unknown_field(value, FIELDS)),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Field, E> {
+        match value {
+            b"len" => Ok(Field::Len),
+            b"data" => Ok(Field::Data),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Bytes(value),
+                &self,
+            )),
+        }
+    }
+}
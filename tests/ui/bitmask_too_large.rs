@@ -0,0 +1,8 @@
+use const_array::ConstArray;
+
+static FLAGS: ConstArray<bool, 65> = ConstArray::from_array([false; 65]);
+
+// Evaluated in a const so that the assertion fires under `cargo check`
+const _: u64 = FLAGS.to_bitmask_u64();
+
+fn main() {}
@@ -0,0 +1,6 @@
+use const_array::ConstArray;
+
+// Evaluated in a const so that the assertion fires under `cargo check`
+const _: () = core::mem::forget(ConstArray::<u32, 2>::new([1, 2, 3]));
+
+fn main() {}
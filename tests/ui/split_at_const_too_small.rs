@@ -0,0 +1,6 @@
+use const_array::ConstArray;
+
+// Evaluated in a const so that the assertion fires under `cargo check`
+const _: () = core::mem::forget(ConstArray::from_array([1u32, 2, 3, 4]).split_at_const::<1, 2>());
+
+fn main() {}